@@ -0,0 +1,181 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Scans `src/yearYYYY/dayN.rs` and generates the module tree along with the `aoc!` invocation
+/// that registers every day. Files starting with an underscore are drafts and are skipped.
+/// Every `examples/yYY/dayN[-k].txt` of a registered day also gets a generated test per part.
+fn main() {
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=aoc.toml");
+    println!("cargo:rerun-if-env-changed=AOC_EXAMPLE_DIR");
+
+    let root = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).to_path_buf();
+    let src = root.join("src");
+
+    let mut years: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+
+    for entry in fs::read_dir(&src).expect("src should exist").flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let year = match name.strip_prefix("year").map(str::parse::<u32>) {
+            Some(Ok(year)) if entry.path().is_dir() => year,
+            _ => continue,
+        };
+
+        let days = years.entry(year).or_default();
+
+        for file in fs::read_dir(entry.path()).unwrap().flatten() {
+            let file_name = file.file_name().to_string_lossy().to_string();
+            let Some(stem) = file_name.strip_suffix(".rs") else {
+                continue;
+            };
+
+            match stem.strip_prefix("day").map(str::parse::<u32>) {
+                Some(Ok(day)) => days.push(day),
+                _ if stem.starts_with('_') => {
+                    println!("cargo:warning=skipping draft src/{}/{}", name, file_name)
+                }
+                _ => {}
+            }
+        }
+
+        days.sort();
+    }
+
+    years.retain(|_, days| !days.is_empty());
+
+    let modules = years
+        .iter()
+        .map(|(year, days)| {
+            let days = days
+                .iter()
+                .map(|d| {
+                    let path = src.join(format!("year{}/day{}.rs", year, d));
+                    format!("    #[path = {:?}]\n    pub mod day{};\n", path, d)
+                })
+                .collect::<String>();
+            format!("pub mod year{} {{\n{}}}\n\n", year, days)
+        })
+        .collect::<String>();
+
+    let registry = years
+        .iter()
+        .map(|(year, days)| {
+            let days = days
+                .iter()
+                .map(|d| d.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("    {} => [{}]", year, days)
+        })
+        .collect::<Vec<_>>()
+        .join(",\n");
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    fs::write(
+        out.join("solutions.rs"),
+        format!("{}aoc!(\n{}\n);\n", modules, registry),
+    )
+    .unwrap();
+    let examples = root.join(example_dir(&root));
+    println!("cargo:rerun-if-changed={}", examples.display());
+    fs::write(out.join("examples.rs"), example_tests(&examples, &years)).unwrap();
+}
+
+/// `AOC_EXAMPLE_DIR`, else `example_dir` from `aoc.toml`, else `examples`
+fn example_dir(root: &Path) -> PathBuf {
+    if let Ok(dir) = env::var("AOC_EXAMPLE_DIR") {
+        return dir.into();
+    }
+
+    fs::read_to_string(root.join("aoc.toml"))
+        .ok()
+        .and_then(|config| config.parse::<toml::Table>().ok())
+        .and_then(|config| config.get("example_dir")?.as_str().map(PathBuf::from))
+        .unwrap_or_else(|| PathBuf::from("examples"))
+}
+
+/// One `#[test]` per part with an answer in the `answers.toml` next to the examples, named
+/// after the file, e.g. `y23_day8_2_part1` for `examples/y23/day8-2.txt`. Parts listed under
+/// `ignore` are known to be broken and are ignored with the given reason. An entry without
+/// answers gets a test that fails until they are filled in, and a file without an entry gets
+/// an ignored one.
+fn example_tests(examples: &Path, years: &BTreeMap<u32, Vec<u32>>) -> String {
+    let mut tests = vec![];
+
+    for (year, days) in years {
+        let dir = examples.join(format!("y{}", year % 2000));
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+
+        let answers_path = dir.join("answers.toml");
+        println!("cargo:rerun-if-changed={}", answers_path.display());
+        let answers = fs::read_to_string(&answers_path)
+            .map(|answers| {
+                answers
+                    .parse::<toml::Table>()
+                    .unwrap_or_else(|e| panic!("Invalid {}: {}", answers_path.display(), e))
+            })
+            .unwrap_or_default();
+
+        for entry in entries.flatten() {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            let Some(stem) = file_name.strip_suffix(".txt") else {
+                continue;
+            };
+            let Some(day) = stem
+                .strip_prefix("day")
+                .and_then(|s| s.split('-').next())
+                .and_then(|d| d.parse::<u32>().ok())
+            else {
+                continue;
+            };
+
+            if !days.contains(&day) {
+                continue;
+            }
+
+            let test = |part: u8, ignore: Option<&str>| {
+                format!(
+                    "#[test]\n{}fn y{}_{}_part{}() {{\n    check_example({}, {}, {:?}, {});\n}}\n",
+                    ignore
+                        .map(|reason| format!("#[ignore = {:?}]\n", reason))
+                        .unwrap_or_default(),
+                    year % 2000,
+                    stem.replace('-', "_"),
+                    part,
+                    year,
+                    day,
+                    entry.path(),
+                    part
+                )
+            };
+
+            let Some(expected) = answers.get(stem).and_then(|e| e.as_table()) else {
+                tests.push(test(1, Some("No expected answers in answers.toml")));
+                continue;
+            };
+            let ignored = expected.get("ignore").and_then(|i| i.as_table());
+            let parts = [1, 2]
+                .into_iter()
+                .filter(|part| expected.contains_key(&format!("part{}", part)))
+                .collect::<Vec<u8>>();
+
+            if parts.is_empty() {
+                tests.push(test(1, None));
+            }
+
+            for part in parts {
+                let reason = ignored
+                    .and_then(|i| i.get(&format!("part{}", part)))
+                    .and_then(|reason| reason.as_str());
+                tests.push(test(part, reason));
+            }
+        }
+    }
+
+    tests.sort();
+    tests.join("\n")
+}
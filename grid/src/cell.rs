@@ -1,7 +1,7 @@
 use std::hash::Hash;
 use std::ops::Deref;
 
-const DELTAS: [(i32, i32); 8] = [
+pub(crate) const DELTAS: [(i32, i32); 8] = [
     (-1, 0),
     (-1, 1),
     (0, 1),
@@ -39,9 +39,9 @@ pub enum Direction {
     NorthWest,
 }
 
-impl<'cell, T, E> Cell<T, E>
+impl<T, E> Cell<T, E>
 where
-    T: Default + Clone + 'cell,
+    T: Default + Clone,
     E: Default + Clone,
 {
     pub fn new(val: T, y: usize, x: usize) -> Self {
@@ -77,22 +77,37 @@ where
     pub fn increment_y(&mut self) {
         self.y += 1;
     }
+}
 
-    pub fn coords(&self) -> (usize, usize) {
-        (self.y, self.x)
+/// Anything with a position on the grid, which gives it neighbors in all eight directions
+pub trait CellLike {
+    fn coords(&self) -> (usize, usize);
+
+    fn signed_coords(&self) -> (i32, i32) {
+        let (y, x) = self.coords();
+        (y as i32, x as i32)
     }
 
-    pub fn get_neighbor(&self, offset: usize) -> (i32, i32) {
+    fn get_neighbor(&self, offset: usize) -> (i32, i32) {
+        let (row, col) = self.signed_coords();
         let (y, x) = DELTAS[offset];
-        (self.y as i32 + y, self.x as i32 + x)
+        (row + y, col + x)
     }
 
-    pub fn neighbors(&self) -> Vec<(i32, i32)> {
-        let (row, col) = self.coords();
-        DELTAS
-            .iter()
-            .map(|(y, x)| (row as i32 + y, col as i32 + x))
-            .collect()
+    fn neighbors(&self) -> Vec<(i32, i32)> {
+        (0..DELTAS.len()).map(|i| self.get_neighbor(i)).collect()
+    }
+}
+
+impl<T, E> CellLike for Cell<T, E> {
+    fn coords(&self) -> (usize, usize) {
+        (self.y, self.x)
+    }
+}
+
+impl CellLike for (usize, usize) {
+    fn coords(&self) -> (usize, usize) {
+        *self
     }
 }
 
@@ -102,11 +117,7 @@ impl<I, E> AsMut<Cell<I, E>> for Cell<I, E> {
     }
 }
 
-impl<T, E> From<&Cell<T, E>> for Vec<(i32, i32)>
-where
-    T: Default + Clone,
-    E: Default + Clone,
-{
+impl<T, E> From<&Cell<T, E>> for Vec<(i32, i32)> {
     fn from(value: &Cell<T, E>) -> Self {
         value.neighbors()
     }
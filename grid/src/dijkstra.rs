@@ -1,36 +1,56 @@
-use std::collections::{BinaryHeap, HashMap};
+use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::hash::Hash;
+use std::marker::PhantomData;
+use std::ops::Add;
+
+/// Anything path costs can be counted in, `Default` being a cost of nothing
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C> Cost for C where C: Copy + Ord + Add<Output = C> + Default {}
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
-pub struct QueueItem<T> {
+pub struct QueueItem<T, C = usize> {
     pub cell: T,
-    pub cost: usize,
+    pub cost: C,
 }
 
-pub struct Dijkstra<'d, T> {
-    neighbors: &'d dyn Fn(T) -> Vec<T>,
-    cost: &'d dyn Fn(&'_ T) -> Option<usize>,
-    end: &'d dyn Fn(&'_ T) -> bool,
+/// Built up with [`Dijkstra::new`]. `neighbors` gives every node reachable from the current
+/// one along with the cost of that move, `goal` stops the search early and `visit` is called
+/// with every node as its cheapest cost becomes final.
+pub struct Dijkstra<T, C, N, G = fn(&T) -> bool, V = fn(&T, C)> {
+    neighbors: N,
+    goal: Option<G>,
+    visit: Option<V>,
+    cost: PhantomData<fn(T) -> C>,
 }
 
-impl<T> QueueItem<T> {
-    pub fn new(cell: T, cost: usize) -> Self {
+/// Everything a single run settled, `goals` being empty when none was reached
+struct Settled<T, C> {
+    distances: HashMap<T, C>,
+    predecessors: HashMap<T, Vec<T>>,
+    goals: Vec<T>,
+}
+
+impl<T, C> QueueItem<T, C> {
+    pub fn new(cell: T, cost: C) -> Self {
         Self { cell, cost }
     }
 }
 
-impl<T> PartialOrd for QueueItem<T>
+impl<T, C> PartialOrd for QueueItem<T, C>
 where
     T: PartialEq + Ord,
+    C: Ord,
 {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl<T> Ord for QueueItem<T>
+impl<T, C> Ord for QueueItem<T, C>
 where
     T: Eq + Ord,
+    C: Ord,
 {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         other
@@ -40,63 +60,244 @@ where
     }
 }
 
-impl<'d, T> Dijkstra<'d, T> {
-    pub fn new(
-        neighbors: &'d dyn Fn(T) -> Vec<T>,
-        cost: &'d dyn Fn(&'_ T) -> Option<usize>,
-        end: &'d dyn Fn(&'_ T) -> bool,
-    ) -> Self {
+impl<T, C> Dijkstra<T, C, ()> {
+    pub fn new() -> Self {
         Self {
+            neighbors: (),
+            goal: None,
+            visit: None,
+            cost: PhantomData,
+        }
+    }
+}
+
+impl<T, C> Default for Dijkstra<T, C, ()> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, C, N, G, V> Dijkstra<T, C, N, G, V> {
+    pub fn neighbors<M, I>(self, neighbors: M) -> Dijkstra<T, C, M, G, V>
+    where
+        M: FnMut(&T) -> I,
+        I: IntoIterator<Item = (T, C)>,
+    {
+        Dijkstra {
             neighbors,
-            cost,
-            end,
+            goal: self.goal,
+            visit: self.visit,
+            cost: PhantomData,
         }
     }
 
-    pub fn cost(&self, start: Vec<T>) -> Option<usize>
+    pub fn goal<H>(self, goal: H) -> Dijkstra<T, C, N, H, V>
     where
-        T: Hash + Eq + PartialEq + Ord + Clone,
+        H: FnMut(&T) -> bool,
     {
-        let mut heat_map: HashMap<T, usize> = HashMap::new();
+        Dijkstra {
+            neighbors: self.neighbors,
+            goal: Some(goal),
+            visit: self.visit,
+            cost: PhantomData,
+        }
+    }
+
+    /// Stops at whichever of `goals` is cheapest to reach
+    pub fn goals(
+        self,
+        goals: impl IntoIterator<Item = T>,
+    ) -> Dijkstra<T, C, N, impl FnMut(&T) -> bool, V>
+    where
+        T: Hash + Eq,
+    {
+        let goals: HashSet<T> = goals.into_iter().collect();
+        self.goal(move |node: &T| goals.contains(node))
+    }
+
+    pub fn on_visit<W>(self, visit: W) -> Dijkstra<T, C, N, G, W>
+    where
+        W: FnMut(&T, C),
+    {
+        Dijkstra {
+            neighbors: self.neighbors,
+            goal: self.goal,
+            visit: Some(visit),
+            cost: PhantomData,
+        }
+    }
+}
+
+impl<T, C, N, I, G, V> Dijkstra<T, C, N, G, V>
+where
+    T: Hash + Eq + Ord + Clone,
+    C: Cost,
+    N: FnMut(&T) -> I,
+    I: IntoIterator<Item = (T, C)>,
+    G: FnMut(&T) -> bool,
+    V: FnMut(&T, C),
+{
+    pub fn cost(&mut self, start: impl IntoIterator<Item = T>) -> Option<C> {
+        self.shortest_paths(start).map(|paths| paths.cost)
+    }
+
+    /// One of the cheapest routes, from a start node to the goal
+    pub fn path(&mut self, start: impl IntoIterator<Item = T>) -> Option<Vec<T>> {
+        self.shortest_paths(start).map(|paths| paths.path())
+    }
+
+    /// Every route to every goal that ties for the cheapest cost
+    pub fn shortest_paths(
+        &mut self,
+        start: impl IntoIterator<Item = T>,
+    ) -> Option<ShortestPaths<T, C>> {
+        let Settled {
+            distances,
+            predecessors,
+            goals,
+        } = self.search(start);
+
+        let cost = *distances.get(goals.first()?)?;
+        Some(ShortestPaths {
+            cost,
+            goals,
+            predecessors,
+        })
+    }
+
+    /// The cheapest cost of every node settled, which is every reachable node without a goal
+    pub fn distances(&mut self, start: impl IntoIterator<Item = T>) -> HashMap<T, C> {
+        self.search(start).distances
+    }
+
+    fn search(&mut self, start: impl IntoIterator<Item = T>) -> Settled<T, C> {
+        let mut heat_map: HashMap<T, C> = HashMap::new();
+        let mut distances: HashMap<T, C> = HashMap::new();
+        let mut predecessors: HashMap<T, Vec<T>> = HashMap::new();
         let mut heap = BinaryHeap::new();
+        let mut goals = vec![];
 
         for s in start {
-            heat_map.insert(s.clone(), 0);
-            heap.push(QueueItem::new(s, 0));
+            heat_map.insert(s.clone(), C::default());
+            heap.push(QueueItem::new(s, C::default()));
         }
 
         while let Some(QueueItem { cost, cell }) = heap.pop() {
-            if (self.end)(&cell) {
-                return Some(cost);
+            // Keep going while there are other goals just as cheap
+            if goals
+                .first()
+                .is_some_and(|goal| distances.get(goal).is_some_and(|&best| cost > best))
+            {
+                break;
             }
 
-            let dist = *heat_map.get(&cell).unwrap_or(&usize::MAX);
+            if distances.contains_key(&cell) || heat_map.get(&cell).is_some_and(|&d| cost > d) {
+                continue;
+            }
+
+            distances.insert(cell.clone(), cost);
+            if let Some(visit) = self.visit.as_mut() {
+                visit(&cell, cost);
+            }
 
-            if cost > dist {
+            if self.goal.as_mut().is_some_and(|goal| goal(&cell)) {
+                goals.push(cell);
                 continue;
             }
 
-            for neighbor in (self.neighbors)(cell.clone()) {
-                // let n_cost = (self.cost)(&cell);
-                let n_cost = match (self.cost)(&cell) {
-                    Some(c) => cost + c,
-                    _ => continue,
-                };
+            for (neighbor, edge) in (self.neighbors)(&cell) {
+                let n_cost = cost + edge;
+
+                match heat_map.get(&neighbor) {
+                    Some(&dist) if n_cost > dist => {}
+                    Some(&dist) if n_cost == dist => {
+                        if !distances.contains_key(&neighbor) {
+                            predecessors.entry(neighbor).or_default().push(cell.clone());
+                        }
+                    }
+                    _ => {
+                        heat_map.insert(neighbor.clone(), n_cost);
+                        predecessors.insert(neighbor.clone(), vec![cell.clone()]);
+                        heap.push(QueueItem::new(neighbor, n_cost));
+                    }
+                }
+            }
+        }
+
+        Settled {
+            distances,
+            predecessors,
+            goals,
+        }
+    }
+}
+
+/// The cheapest routes found by [`Dijkstra::shortest_paths`]. `predecessors` maps each node
+/// to the nodes it can be reached from at its cheapest cost.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<T, C = usize> {
+    pub cost: C,
+    pub goals: Vec<T>,
+    pub predecessors: HashMap<T, Vec<T>>,
+}
+
+impl<T, C> ShortestPaths<T, C>
+where
+    T: Hash + Eq + Clone,
+{
+    pub fn path(&self) -> Vec<T> {
+        let mut path = vec![];
+        let mut node = self.goals.first().cloned();
+
+        while let Some(current) = node {
+            node = self
+                .predecessors
+                .get(&current)
+                .and_then(|p| p.first())
+                .cloned();
+            path.push(current);
+        }
+
+        path.reverse();
+        path
+    }
+
+    pub fn paths(&self) -> Vec<Vec<T>> {
+        let mut paths = vec![];
 
-                let dist_to_next = heat_map.get(&neighbor).unwrap_or(&usize::MAX);
+        for goal in &self.goals {
+            self.walk(&mut vec![goal.clone()], &mut paths);
+        }
+
+        paths
+    }
+
+    fn walk(&self, path: &mut Vec<T>, paths: &mut Vec<Vec<T>>) {
+        match self.predecessors.get(path.last().unwrap()) {
+            Some(previous) if !previous.is_empty() => {
+                for p in previous {
+                    path.push(p.clone());
+                    self.walk(path, paths);
+                    path.pop();
+                }
+            }
+            _ => paths.push(path.iter().rev().cloned().collect()),
+        }
+    }
 
-                if n_cost < *dist_to_next {
-                    heat_map
-                        .entry(neighbor.clone())
-                        .and_modify(|c| *c = n_cost)
-                        .or_insert(n_cost);
-                    let next = QueueItem::new(neighbor, n_cost);
+    /// Every node on at least one of the paths
+    pub fn nodes(&self) -> HashSet<T> {
+        let mut nodes: HashSet<T> = self.goals.iter().cloned().collect();
+        let mut stack = self.goals.clone();
 
-                    heap.push(next);
+        while let Some(node) = stack.pop() {
+            for p in self.predecessors.get(&node).into_iter().flatten() {
+                if nodes.insert(p.clone()) {
+                    stack.push(p.clone());
                 }
             }
         }
 
-        None
+        nodes
     }
 }
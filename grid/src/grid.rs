@@ -9,13 +9,13 @@ use crate::cell::*;
 pub type DefaultGrid<T> = Grid<T, ()>;
 pub type Coords = (usize, usize);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Sided {
     Four,
     Eight,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Grid<T, E> {
     pub grid: Vec<Cell<T, E>>,
     pub rows: usize,
@@ -81,10 +81,7 @@ where
         default: Option<T>,
         sides: Option<Sided>,
     ) -> Self {
-        let value = match default {
-            Some(val) => val,
-            None => T::default(),
-        };
+        let value = default.unwrap_or_default();
 
         let n_neighbors = match sides {
             Some(side) => side,
@@ -152,13 +149,33 @@ where
         (y < self.rows && x < self.cols).then(|| &mut self[(y, x)])
     }
 
-    pub fn get_cell_signed(&self, coords: (i32, i32)) -> Option<&Cell<T, E>> {
+    pub fn contains_signed(&self, coords: (i32, i32)) -> bool {
         let (y, x) = coords;
-        if (y >= 0 && x >= 0 && y < (self.rows as i32) && x < (self.cols as i32)) {
-            Some(&self[(y as usize, x as usize)])
-        } else {
-            None
-        }
+        y >= 0 && x >= 0 && y < (self.rows as i32) && x < (self.cols as i32)
+    }
+
+    pub fn get_cell_signed(&self, coords: (i32, i32)) -> Option<&Cell<T, E>> {
+        self.contains_signed(coords)
+            .then(|| &self[(coords.0 as usize, coords.1 as usize)])
+    }
+
+    pub fn get_cell_signed_mut(&mut self, coords: (i32, i32)) -> Option<&mut Cell<T, E>> {
+        self.contains_signed(coords)
+            .then(|| &mut self[(coords.0 as usize, coords.1 as usize)])
+    }
+
+    /// Coordinates around `cell` that are on the grid, following `n_neighbors`
+    pub fn neighbor_coords<C: CellLike>(&self, cell: &C) -> Vec<(i32, i32)> {
+        let step = match self.n_neighbors {
+            Sided::Four => 2,
+            Sided::Eight => 1,
+        };
+
+        cell.neighbors()
+            .into_iter()
+            .step_by(step)
+            .filter(|&coords| self.contains_signed(coords))
+            .collect()
     }
 
     pub fn get_neighbor(&self, coords: (usize, usize), offset: usize) -> Option<&Cell<T, E>> {
@@ -281,7 +298,7 @@ where
         self.grid.iter_mut()
     }
 
-    pub fn iter_enum(&'g self) -> impl Iterator<Item = (usize, usize, &Cell<T, E>)> {
+    pub fn iter_enum(&'g self) -> impl Iterator<Item = (usize, usize, &'g Cell<T, E>)> {
         GridIter {
             grid: &self.grid,
             idx: 0,
@@ -376,6 +393,29 @@ where
     }
 }
 
+impl<E> Grid<char, E>
+where
+    E: Default + Clone,
+{
+    /// One cell per character, nothing is skipped apart from line endings
+    pub fn from_chars(input: &str, neighbors: Sided) -> Self {
+        let lines = input.trim_end().lines().collect::<Vec<_>>();
+        let grid = lines
+            .iter()
+            .enumerate()
+            .flat_map(|(y, l)| l.chars().enumerate().map(move |(x, c)| Cell::new(c, y, x)))
+            .collect();
+
+        Grid {
+            grid,
+            rows: lines.len(),
+            cols: lines.first().map_or(0, |l| l.chars().count()),
+            n_neighbors: neighbors,
+            default: char::default(),
+        }
+    }
+}
+
 impl<T, E> Index<(usize, usize)> for Grid<T, E> {
     type Output = Cell<T, E>;
 
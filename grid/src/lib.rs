@@ -2,14 +2,22 @@ pub mod cell;
 pub mod dijkstra;
 pub mod grid;
 pub mod macros;
+pub mod maze;
+pub mod search;
+pub mod sparse;
 pub mod tests;
+pub mod tiled;
 
 pub mod prelude {
 
     pub use super::{
-        cell::{Cell, Cursor, Direction, Directional, IntoCell},
-        dijkstra::{Dijkstra, QueueItem},
+        cell::{Cell, CellLike, Cursor, Direction, Directional, IntoCell},
+        dijkstra::{Cost, Dijkstra, QueueItem, ShortestPaths},
         grid,
         grid::{Coords, DefaultGrid, Grid, GridIter, Sided},
+        maze::JunctionGraph,
+        search::{astar, bfs, dfs, dijkstra, Search},
+        sparse::{SparseCoords, SparseGrid},
+        tiled::{TiledGrid, Wrap},
     };
 }
@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+
+use crate::cell::CellLike;
+use crate::grid::{Coords, Grid};
+
+/// Moves in the order north, east, south, west, along with the slope that allows each
+pub(crate) const STEPS: [((i32, i32), char); 4] =
+    [((-1, 0), '^'), ((0, 1), '>'), ((1, 0), 'v'), ((0, -1), '<')];
+
+/// A maze reduced to its junctions, the corridors between them becoming weighted edges
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JunctionGraph {
+    pub nodes: Vec<Coords>,
+    /// `edges[i]` has `(j, steps)` for every corridor that can be walked from node `i` to `j`
+    pub edges: Vec<Vec<(usize, usize)>>,
+    pub start: usize,
+    pub end: usize,
+}
+
+fn slope(c: char) -> Option<(i32, i32)> {
+    STEPS
+        .iter()
+        .find(|&&(_, s)| s == c)
+        .map(|&(delta, _)| delta)
+}
+
+/// Where a step from `from` by `delta` lands, if it isn't a wall or against a slope
+pub(crate) fn step<E>(
+    grid: &Grid<char, E>,
+    from: Coords,
+    delta: (i32, i32),
+    slopes: bool,
+) -> Option<Coords>
+where
+    E: Default + Clone,
+{
+    let to = (from.0 as i32 + delta.0, from.1 as i32 + delta.1);
+    let cell = grid.get_cell_signed(to).filter(|c| c.val != '#')?;
+
+    if slopes {
+        let leaving = slope(grid[from].val).is_none_or(|s| s == delta);
+        let entering = slope(cell.val).is_none_or(|s| s != (-delta.0, -delta.1));
+        if !leaving || !entering {
+            return None;
+        }
+    }
+
+    Some(cell.coords())
+}
+
+impl JunctionGraph {
+    /// `#` is a wall, and when `slopes` is set `^>v<` can only be crossed the way they point.
+    /// Junctions are `start`, `end` and any open cell with three or more open neighbors.
+    /// `None` when `start` or `end` is a wall or off the grid.
+    pub fn new<E>(grid: &Grid<char, E>, start: Coords, end: Coords, slopes: bool) -> Option<Self>
+    where
+        E: Default + Clone,
+    {
+        let nodes = grid
+            .iter()
+            .map(|c| c.coords())
+            .filter(|&c| grid[c].val != '#')
+            .filter(|&c| {
+                let open = STEPS
+                    .iter()
+                    .filter(|&&(delta, _)| step(grid, c, delta, false).is_some())
+                    .count();
+                c == start || c == end || open >= 3
+            })
+            .collect::<Vec<_>>();
+
+        let index: HashMap<Coords, usize> =
+            nodes.iter().enumerate().map(|(i, &c)| (c, i)).collect();
+        let mut edges = vec![vec![]; nodes.len()];
+
+        for (i, &node) in nodes.iter().enumerate() {
+            for &(delta, _) in &STEPS {
+                let (mut prev, mut current) = match step(grid, node, delta, slopes) {
+                    Some(next) => (node, next),
+                    None => continue,
+                };
+                let mut steps = 1;
+
+                // Follow the corridor until it reaches a junction or a dead end
+                loop {
+                    if let Some(&j) = index.get(&current) {
+                        edges[i].push((j, steps));
+                        break;
+                    }
+
+                    let next = STEPS
+                        .iter()
+                        .filter_map(|&(delta, _)| step(grid, current, delta, slopes))
+                        .find(|&n| n != prev);
+
+                    match next {
+                        Some(next) => {
+                            (prev, current) = (current, next);
+                            steps += 1;
+                        }
+                        None => break,
+                    }
+                }
+            }
+        }
+
+        Some(Self {
+            start: *index.get(&start)?,
+            end: *index.get(&end)?,
+            nodes,
+            edges,
+        })
+    }
+
+    /// Steps in the longest path from `start` to `end` that never visits a cell twice
+    pub fn longest_path(&self) -> Option<usize> {
+        self.longest_with(self.last_junction())
+    }
+
+    /// With a single way into `end`, the junction it comes from. Reaching that junction means
+    /// going straight to `end`, anything else would cut it off.
+    pub(crate) fn last_junction(&self) -> Option<usize> {
+        let mut into_end = (0..self.nodes.len())
+            .filter(|&i| i != self.end && self.edges[i].iter().any(|&(j, _)| j == self.end));
+
+        match (into_end.next(), into_end.next()) {
+            (Some(last), None) => Some(last),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn longest_with(&self, last: Option<usize>) -> Option<usize> {
+        let mut visited = Bitset::new(self.nodes.len());
+        visited.insert(self.start);
+
+        self.dfs(self.start, &mut visited, last)
+    }
+
+    fn dfs(&self, node: usize, visited: &mut Bitset, last: Option<usize>) -> Option<usize> {
+        if node == self.end {
+            return Some(0);
+        }
+
+        let mut longest = None;
+        for &(next, steps) in &self.edges[node] {
+            if visited.contains(next) || (last == Some(node) && next != self.end) {
+                continue;
+            }
+
+            visited.insert(next);
+            if let Some(rest) = self.dfs(next, visited, last) {
+                longest = longest.max(Some(rest + steps));
+            }
+            visited.remove(next);
+        }
+
+        longest
+    }
+}
+
+/// The junctions on the current path, one bit each in as many words as the graph needs
+struct Bitset(Vec<u64>);
+
+impl Bitset {
+    fn new(len: usize) -> Self {
+        Self(vec![0; len.div_ceil(64)])
+    }
+
+    fn contains(&self, i: usize) -> bool {
+        self.0[i / 64] & (1 << (i % 64)) != 0
+    }
+
+    fn insert(&mut self, i: usize) {
+        self.0[i / 64] |= 1 << (i % 64);
+    }
+
+    fn remove(&mut self, i: usize) {
+        self.0[i / 64] &= !(1 << (i % 64));
+    }
+}
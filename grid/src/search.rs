@@ -0,0 +1,192 @@
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::dijkstra::{Cost, QueueItem};
+
+/// What a search found. `distances` has every node that was reached, which for [`bfs`]
+/// includes nodes discovered but never expanded. `path` runs from a start node to `goal` and
+/// is empty when no goal was reached.
+#[derive(Debug, Clone)]
+pub struct Search<N, C = usize> {
+    pub distances: HashMap<N, C>,
+    pub goal: Option<N>,
+    pub path: Vec<N>,
+}
+
+impl<N, C> Search<N, C>
+where
+    N: Hash + Eq + Clone,
+    C: Copy,
+{
+    fn new(distances: HashMap<N, C>, parents: &HashMap<N, N>, goal: Option<N>) -> Self {
+        let mut path = vec![];
+        let mut node = goal.clone();
+
+        while let Some(current) = node {
+            node = parents.get(&current).cloned();
+            path.push(current);
+        }
+        path.reverse();
+
+        Self {
+            distances,
+            goal,
+            path,
+        }
+    }
+
+    /// Distance to the goal, if one was reached
+    pub fn cost(&self) -> Option<C> {
+        self.goal.as_ref().map(|goal| self.distances[goal])
+    }
+}
+
+/// Breadth first, every edge costs 1
+pub fn bfs<N, I>(
+    start: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for node in start {
+        if !distances.contains_key(&node) {
+            distances.insert(node.clone(), 0);
+            queue.push_back(node);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if goal(&node) {
+            return Search::new(distances, &parents, Some(node));
+        }
+
+        let dist = distances[&node] + 1;
+        for next in neighbors(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), dist);
+                parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    Search::new(distances, &parents, None)
+}
+
+/// Depth first, distances are the depth each node was first reached at so they aren't
+/// necessarily the shortest
+pub fn dfs<N, I>(
+    start: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut stack = start
+        .into_iter()
+        .map(|node| (node, 0, None))
+        .collect::<Vec<_>>();
+    stack.reverse();
+
+    while let Some((node, dist, parent)) = stack.pop() {
+        if distances.contains_key(&node) {
+            continue;
+        }
+
+        distances.insert(node.clone(), dist);
+        if let Some(parent) = parent {
+            parents.insert(node.clone(), parent);
+        }
+
+        if goal(&node) {
+            return Search::new(distances, &parents, Some(node));
+        }
+
+        let mut next = neighbors(&node)
+            .into_iter()
+            .filter(|n| !distances.contains_key(n))
+            .map(|n| (n, dist + 1, Some(node.clone())))
+            .collect::<Vec<_>>();
+        // Reversed so neighbors are explored in the order they were given
+        next.reverse();
+        stack.extend(next);
+    }
+
+    Search::new(distances, &parents, None)
+}
+
+/// Cheapest first, `neighbors` gives each next node with the cost of moving to it
+pub fn dijkstra<N, C, I>(
+    start: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Hash + Eq + Ord + Clone,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbors, goal, |_| C::default())
+}
+
+/// [`dijkstra`] guided by `heuristic`, which must be consistent: it never drops by more than
+/// the cost of a move. Settled nodes are never reopened, so a heuristic that only never
+/// overestimates can give a path that isn't the cheapest.
+pub fn astar<N, C, I>(
+    start: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+    mut heuristic: impl FnMut(&N) -> C,
+) -> Search<N, C>
+where
+    N: Hash + Eq + Ord + Clone,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut best = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut distances = HashMap::new();
+    let mut heap = BinaryHeap::new();
+
+    for node in start {
+        best.insert(node.clone(), C::default());
+        heap.push(QueueItem::new(node.clone(), heuristic(&node)));
+    }
+
+    while let Some(QueueItem { cell, .. }) = heap.pop() {
+        if distances.contains_key(&cell) {
+            continue;
+        }
+
+        let cost = best[&cell];
+        distances.insert(cell.clone(), cost);
+
+        if goal(&cell) {
+            return Search::new(distances, &parents, Some(cell));
+        }
+
+        for (next, edge) in neighbors(&cell) {
+            let next_cost = cost + edge;
+            if distances.contains_key(&next) || best.get(&next).is_some_and(|&c| c <= next_cost) {
+                continue;
+            }
+
+            best.insert(next.clone(), next_cost);
+            parents.insert(next.clone(), cell.clone());
+            heap.push(QueueItem::new(next.clone(), next_cost + heuristic(&next)));
+        }
+    }
+
+    Search::new(distances, &parents, None)
+}
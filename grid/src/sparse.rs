@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use std::iter::FromIterator;
+use std::ops::{Index, IndexMut};
+
+use crate::cell::{IntoCell, DELTAS};
+use crate::grid::Sided;
+
+pub type SparseCoords = (i64, i64);
+
+/// A grid without fixed edges, only the cells that were set are stored. Coordinates are
+/// `(y, x)` and may go negative, `bounds` grows and shrinks with the cells.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<SparseCoords, T>,
+    bounds: Option<(SparseCoords, SparseCoords)>,
+    pub n_neighbors: Sided,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn empty(neighbors: Sided) -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+            n_neighbors: neighbors,
+        }
+    }
+
+    /// Same as [`crate::grid::Grid::new`], with the first line at `y = 0`
+    pub fn new<'g>(input: &'g str, neighbors: Sided) -> Self
+    where
+        T: IntoCell<'g, T>,
+    {
+        let mut grid = Self::empty(neighbors);
+
+        for (y, l) in input.trim().lines().enumerate() {
+            for (x, c) in l
+                .trim()
+                .split("")
+                .filter(|v| !v.trim().is_empty())
+                .enumerate()
+            {
+                grid.insert((y as i64, x as i64), T::from_str::<()>(c, y, x).val);
+            }
+        }
+
+        grid
+    }
+
+    pub fn with_sides(mut self, neighbors: Sided) -> Self {
+        self.n_neighbors = neighbors;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Top left and bottom right corners of the set cells, inclusive
+    pub fn bounds(&self) -> Option<(SparseCoords, SparseCoords)> {
+        self.bounds
+    }
+
+    pub fn rows(&self) -> usize {
+        self.bounds
+            .map_or(0, |((min_y, _), (max_y, _))| (max_y - min_y + 1) as usize)
+    }
+
+    pub fn cols(&self) -> usize {
+        self.bounds
+            .map_or(0, |((_, min_x), (_, max_x))| (max_x - min_x + 1) as usize)
+    }
+
+    pub fn contains(&self, coords: SparseCoords) -> bool {
+        self.cells.contains_key(&coords)
+    }
+
+    pub fn get_cell(&self, coords: SparseCoords) -> Option<&T> {
+        self.cells.get(&coords)
+    }
+
+    pub fn get_cell_mut(&mut self, coords: SparseCoords) -> Option<&mut T> {
+        self.cells.get_mut(&coords)
+    }
+
+    pub fn insert(&mut self, coords: SparseCoords, val: T) -> Option<T> {
+        let (y, x) = coords;
+        self.bounds = Some(match self.bounds {
+            Some(((min_y, min_x), (max_y, max_x))) => {
+                ((min_y.min(y), min_x.min(x)), (max_y.max(y), max_x.max(x)))
+            }
+            None => (coords, coords),
+        });
+
+        self.cells.insert(coords, val)
+    }
+
+    pub fn remove(&mut self, coords: SparseCoords) -> Option<T> {
+        let removed = self.cells.remove(&coords)?;
+
+        // Only a cell on the edge can move the bounds
+        if let Some(((min_y, min_x), (max_y, max_x))) = self.bounds {
+            let (y, x) = coords;
+            if y == min_y || y == max_y || x == min_x || x == max_x {
+                self.bounds = self.cells.keys().fold(None, |bounds, &(y, x)| {
+                    Some(match bounds {
+                        Some(((min_y, min_x), (max_y, max_x))) => {
+                            ((y.min(min_y), x.min(min_x)), (y.max(max_y), x.max(max_x)))
+                        }
+                        None => ((y, x), (y, x)),
+                    })
+                });
+            }
+        }
+
+        Some(removed)
+    }
+
+    pub fn get_neighbor(&self, coords: SparseCoords, offset: usize) -> Option<&T> {
+        let (dy, dx) = DELTAS[offset];
+        self.get_cell((coords.0 + dy as i64, coords.1 + dx as i64))
+    }
+
+    /// Every coordinate around `coords` following `n_neighbors`, set or not
+    pub fn neighbor_coords(&self, coords: SparseCoords) -> Vec<SparseCoords> {
+        let step = match self.n_neighbors {
+            Sided::Four => 2,
+            Sided::Eight => 1,
+        };
+
+        DELTAS
+            .iter()
+            .step_by(step)
+            .map(|&(dy, dx)| (coords.0 + dy as i64, coords.1 + dx as i64))
+            .collect()
+    }
+
+    /// The neighbors of `coords` that are set
+    pub fn neighbors(&self, coords: SparseCoords) -> Vec<(SparseCoords, &T)> {
+        self.neighbor_coords(coords)
+            .into_iter()
+            .filter_map(|n| self.get_cell(n).map(|val| (n, val)))
+            .collect()
+    }
+
+    /// Set cells in no particular order, see [`SparseGrid::iter_rows`] for a stable one
+    pub fn iter(&self) -> impl Iterator<Item = (SparseCoords, &T)> {
+        self.cells.iter().map(|(&coords, val)| (coords, val))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (SparseCoords, &mut T)> {
+        self.cells.iter_mut().map(|(&coords, val)| (coords, val))
+    }
+
+    /// Set cells top to bottom, left to right
+    pub fn iter_rows(&self) -> impl Iterator<Item = (SparseCoords, &T)> {
+        let mut cells = self.iter().collect::<Vec<_>>();
+        cells.sort_by_key(|&(coords, _)| coords);
+        cells.into_iter()
+    }
+}
+
+impl SparseGrid<char> {
+    /// One cell per character, with `skip` left unset
+    pub fn from_chars(input: &str, skip: char, neighbors: Sided) -> Self {
+        input
+            .lines()
+            .enumerate()
+            .flat_map(|(y, l)| {
+                l.chars()
+                    .enumerate()
+                    .filter(move |&(_, c)| c != skip)
+                    .map(move |(x, c)| ((y as i64, x as i64), c))
+            })
+            .collect::<SparseGrid<char>>()
+            .with_sides(neighbors)
+    }
+}
+
+impl<T> Index<SparseCoords> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, index: SparseCoords) -> &Self::Output {
+        &self.cells[&index]
+    }
+}
+
+impl<T> IndexMut<SparseCoords> for SparseGrid<T> {
+    fn index_mut(&mut self, index: SparseCoords) -> &mut Self::Output {
+        self.cells
+            .get_mut(&index)
+            .expect("no cell at these coordinates")
+    }
+}
+
+impl<T> FromIterator<(SparseCoords, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (SparseCoords, T)>>(iter: I) -> Self {
+        let mut grid = Self::empty(Sided::Four);
+        for (coords, val) in iter {
+            grid.insert(coords, val);
+        }
+        grid
+    }
+}
@@ -1,5 +1,8 @@
 #![cfg(test)]
-use crate::{cell::*, grid, grid::*};
+use std::collections::HashSet;
+
+use crate::maze::step as maze_step;
+use crate::{cell::*, dijkstra::*, grid, grid::*, maze::*, search::*, sparse::*, tiled::*};
 
 const TEST_GRID: &str = "\
 0 0 0 0 0
@@ -239,3 +242,474 @@ fn grid_macro() {
 
     assert_eq!(grid, expect);
 }
+
+#[test]
+fn from_chars() {
+    let grid: DefaultGrid<char> = Grid::from_chars("#.#\n. #\n", Sided::Four);
+
+    assert_eq!((grid.rows, grid.cols), (2, 3));
+    assert_eq!(grid[(1, 1)].val, ' ');
+    assert_eq!(grid.iter().map(|c| c.val).collect::<String>(), "#.#. #");
+}
+
+#[test]
+fn signed_access() {
+    let mut grid: DefaultGrid<u8> = Grid::new(TEST_GRID, Sided::Four);
+
+    assert!(grid.contains_signed((4, 4)));
+    assert!(!grid.contains_signed((-1, 0)));
+    assert!(!grid.contains_signed((0, 5)));
+    assert_eq!(grid.get_cell_signed((-1, 2)), None);
+
+    grid.get_cell_signed_mut((3, 1)).unwrap().val = 7;
+    assert_eq!(grid[(3, 1)].val, 7);
+}
+
+#[test]
+fn cell_like() {
+    let mut grid: DefaultGrid<u8> = Grid::new(TEST_GRID, Sided::Four);
+
+    assert_eq!(grid.neighbor_coords(&(0, 0)), vec![(0, 1), (1, 0)]);
+    assert_eq!(
+        grid.neighbor_coords(&grid[(1, 1)]),
+        vec![(0, 1), (1, 2), (2, 1), (1, 0)]
+    );
+    assert_eq!((2, 2).get_neighbor(5), (3, 1));
+
+    grid.n_neighbors = Sided::Eight;
+    assert_eq!(grid.neighbor_coords(&(4, 4)), vec![(3, 4), (4, 3), (3, 3)]);
+}
+
+#[test]
+fn transpose() {
+    let grid: DefaultGrid<u32> = Grid::new("6420\n7531", Sided::Four);
+    let transposed: DefaultGrid<u32> = Grid::new("01\n23\n45\n67", Sided::Four).transpose();
+
+    assert_eq!(grid, transposed);
+}
+
+#[test]
+fn sparse_bounds() {
+    let mut grid: SparseGrid<u8> = SparseGrid::empty(Sided::Four);
+    assert_eq!(grid.bounds(), None);
+
+    grid.insert((0, 0), 1);
+    grid.insert((-3, 2), 2);
+    grid.insert((4, -1), 3);
+
+    assert_eq!(grid.bounds(), Some(((-3, -1), (4, 2))));
+    assert_eq!((grid.rows(), grid.cols()), (8, 4));
+
+    assert_eq!(grid.remove((0, 0)), Some(1));
+    assert_eq!(grid.bounds(), Some(((-3, -1), (4, 2))));
+
+    grid.remove((-3, 2));
+    assert_eq!(grid.bounds(), Some(((4, -1), (4, -1))));
+
+    grid.remove((4, -1));
+    assert!(grid.is_empty());
+    assert_eq!(grid.bounds(), None);
+}
+
+#[test]
+fn sparse_neighbors() {
+    let mut grid = SparseGrid::from_chars("#.#\n.#.\n", '.', Sided::Four);
+    assert_eq!(grid.len(), 3);
+    assert_eq!(grid.neighbors((1, 1)), vec![]);
+    assert_eq!(
+        grid.neighbor_coords((0, 0)),
+        vec![(-1, 0), (0, 1), (1, 0), (0, -1)]
+    );
+
+    grid.n_neighbors = Sided::Eight;
+    assert_eq!(grid.neighbors((1, 1)), vec![((0, 2), &'#'), ((0, 0), &'#')]);
+    assert_eq!(grid.get_neighbor((1, 1), 7), Some(&'#'));
+
+    grid[(0, 0)] = 'x';
+    grid.insert((-1, -1), '#');
+    assert_eq!(
+        grid.neighbors((0, 0)),
+        vec![((1, 1), &'#'), ((-1, -1), &'#')]
+    );
+}
+
+#[test]
+fn sparse_iter() {
+    let grid: SparseGrid<u8> = SparseGrid::new("12\n34", Sided::Four);
+
+    assert_eq!(
+        grid.iter_rows().collect::<Vec<_>>(),
+        vec![((0, 0), &1), ((0, 1), &2), ((1, 0), &3), ((1, 1), &4)]
+    );
+
+    let grid: SparseGrid<u8> = grid.iter().map(|((y, x), &v)| ((-y, x), v)).collect();
+    assert_eq!(grid.bounds(), Some(((-1, 0), (0, 1))));
+    assert_eq!(grid[(-1, 1)], 4);
+}
+
+#[test]
+fn tiled() {
+    let grid: DefaultGrid<u8> = Grid::new("12\n34\n56", Sided::Four);
+    let tiled = grid.tiled();
+
+    assert_eq!(tiled.base_coords((-1, -1)), (2, 1));
+    assert_eq!(tiled.tile((-1, -1)), (-1, -1));
+    assert_eq!(tiled.tile((7, 3)), (2, 1));
+    assert_eq!(tiled.get_cell((7, 3)).val, 4);
+    assert_eq!(tiled.get_cell((-3, -2)).coords(), (0, 0));
+
+    assert_eq!(
+        tiled
+            .neighbors((0, 0))
+            .iter()
+            .map(|(coords, cell)| (*coords, cell.val))
+            .collect::<Vec<_>>(),
+        vec![((-1, 0), 5), ((0, 1), 2), ((1, 0), 3), ((0, -1), 2)]
+    );
+}
+
+#[test]
+fn torus() {
+    let grid: DefaultGrid<u8> = Grid::new("12\n34\n56", Sided::Eight);
+    let torus = TiledGrid::new(&grid, Wrap::Torus);
+
+    assert_eq!(
+        torus.neighbor_coords((0, 0)),
+        vec![
+            (2, 0),
+            (2, 1),
+            (0, 1),
+            (1, 1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (2, 1)
+        ]
+    );
+    assert_eq!(torus.get_neighbor((2, 1), 3), (0, 0));
+}
+
+const MAZE: &str = "\
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#G
+";
+
+fn maze() -> DefaultGrid<char> {
+    Grid::from_chars(MAZE, Sided::Four)
+}
+
+fn open_neighbors(grid: &DefaultGrid<char>, coords: &Coords) -> Vec<Coords> {
+    grid.neighbor_coords(coords)
+        .into_iter()
+        .map(|(y, x)| (y as usize, x as usize))
+        .filter(|&c| grid[c].val != '#')
+        .collect()
+}
+
+fn is_path(grid: &DefaultGrid<char>, path: &[Coords]) -> bool {
+    path.windows(2)
+        .all(|w| open_neighbors(grid, &w[0]).contains(&w[1]))
+}
+
+#[test]
+fn search_bfs() {
+    let grid = maze();
+    let goal = grid.find('G').unwrap().coords();
+    let search = bfs([(0, 0)], |c| open_neighbors(&grid, c), |&c| c == goal);
+
+    assert_eq!(search.goal, Some(goal));
+    assert_eq!(search.cost(), Some(15));
+    assert_eq!(search.path.len(), 16);
+    assert_eq!(search.path.first(), Some(&(0, 0)));
+    assert!(is_path(&grid, &search.path));
+
+    let search = bfs([(0, 0)], |c| open_neighbors(&grid, c), |_| false);
+    assert_eq!(search.goal, None);
+    assert!(search.path.is_empty());
+    assert_eq!(
+        search.distances.len(),
+        MAZE.matches(['.', 'S', 'G']).count()
+    );
+    assert_eq!(search.distances[&(4, 0)], 4);
+}
+
+#[test]
+fn search_dfs() {
+    let grid = maze();
+    let goal = grid.find('G').unwrap().coords();
+    let search = dfs([(0, 0)], |c| open_neighbors(&grid, c), |&c| c == goal);
+
+    assert_eq!(search.goal, Some(goal));
+    assert_eq!(search.cost(), Some(search.path.len() - 1));
+    assert!(is_path(&grid, &search.path));
+
+    let search = dfs([(0, 0)], |c| open_neighbors(&grid, c), |_| false);
+    assert_eq!(
+        search.distances.len(),
+        MAZE.matches(['.', 'S', 'G']).count()
+    );
+}
+
+#[test]
+fn search_weighted() {
+    let grid: DefaultGrid<u8> = Grid::new("1163\n1381\n2136\n3694", Sided::Four);
+    let end = (3, 3);
+    let weighted = |coords: &Coords| {
+        grid.neighbor_coords(coords)
+            .into_iter()
+            .map(|(y, x)| {
+                (
+                    (y as usize, x as usize),
+                    grid[(y as usize, x as usize)].val as usize,
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let search = dijkstra([(0, 0)], weighted, |&c| c == end);
+    assert_eq!(search.cost(), Some(1 + 2 + 1 + 3 + 6 + 4));
+    assert_eq!(
+        search.path,
+        vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (2, 3), (3, 3)]
+    );
+
+    let manhattan = |&(y, x): &Coords| (end.0 - y) + (end.1 - x);
+    let guided = astar([(0, 0)], weighted, |&c| c == end, manhattan);
+    assert_eq!(guided.cost(), search.cost());
+    assert!(guided.distances.len() <= search.distances.len());
+
+    let all = dijkstra([(0, 0)], weighted, |_| false);
+    assert_eq!(all.distances.len(), 16);
+    assert_eq!(all.distances[&end], 17);
+}
+
+#[test]
+fn dijkstra_paths() {
+    // Two equally short routes through 1 and 2, and a longer one through 5
+    let neighbors = |node: &u8| match node {
+        0 => vec![(1, 1), (2, 1), (5, 1)],
+        1 | 2 => vec![(3, 1)],
+        3 => vec![(4, 1)],
+        5 => vec![(6, 2)],
+        6 => vec![(4, 1)],
+        _ => vec![],
+    };
+    let mut dijkstra = Dijkstra::new()
+        .neighbors(neighbors)
+        .goal(|node: &u8| *node == 4);
+
+    assert_eq!(dijkstra.cost([0]), Some(3));
+    let path = dijkstra.path([0]).unwrap();
+    assert!(path == [0, 1, 3, 4] || path == [0, 2, 3, 4]);
+
+    let paths = dijkstra.shortest_paths([0]).unwrap();
+    assert_eq!(paths.cost, 3);
+    assert_eq!(paths.goals, vec![4]);
+    assert_eq!(paths.predecessors[&3].len(), 2);
+
+    let mut all = paths.paths();
+    all.sort();
+    assert_eq!(all, vec![vec![0, 1, 3, 4], vec![0, 2, 3, 4]]);
+    assert_eq!(paths.nodes(), HashSet::from([0, 1, 2, 3, 4]));
+
+    let mut unreachable = Dijkstra::new().neighbors(neighbors).goal(|n: &u8| *n == 9);
+    assert!(unreachable.shortest_paths([0]).is_none());
+}
+
+#[test]
+fn dijkstra_edges() {
+    // Moving right is cheap, moving down costs the value of the cell entered
+    let grid: DefaultGrid<u8> = Grid::new("11\n19", Sided::Four);
+    let neighbors = |&(y, x): &Coords| {
+        let mut next = vec![];
+        if x + 1 < grid.cols {
+            next.push(((y, x + 1), 1_i64));
+        }
+        if y + 1 < grid.rows {
+            next.push(((y + 1, x), grid[(y + 1, x)].val as i64 * 10));
+        }
+        next
+    };
+    let end = |&c: &Coords| c == (1, 1);
+
+    let paths = Dijkstra::new()
+        .neighbors(neighbors)
+        .goal(end)
+        .shortest_paths([(0, 0)])
+        .unwrap();
+    assert_eq!(paths.cost, 11_i64);
+    assert_eq!(paths.path(), vec![(0, 0), (1, 0), (1, 1)]);
+
+    let search = dijkstra([(0, 0)], neighbors, end);
+    assert_eq!(search.cost(), Some(11_i64));
+    assert_eq!(search.distances[&(0, 1)], 1);
+
+    let cost = Dijkstra::new()
+        .neighbors(|&n: &u64| [(n + 1, u64::MAX / 4)])
+        .goal(|&n: &u64| n == 2)
+        .cost([0]);
+    assert_eq!(cost, Some(u64::MAX / 4 * 2));
+}
+
+#[test]
+fn dijkstra_builder() {
+    // A line 0 - 1 - ... - 9 where every step costs the node moved into
+    let line = |&n: &u32| {
+        [n.checked_sub(1), (n < 9).then_some(n + 1)]
+            .into_iter()
+            .flatten()
+            .map(|next| (next, next as u64))
+    };
+
+    let mut visited = vec![];
+    let distances = Dijkstra::new()
+        .neighbors(line)
+        .on_visit(|&n: &u32, cost| visited.push((n, cost)))
+        .distances([4]);
+
+    assert_eq!(distances.len(), 10);
+    assert_eq!(distances[&0], 3 + 2 + 1);
+    assert_eq!(distances[&9], 5 + 6 + 7 + 8 + 9);
+    assert_eq!(visited.first(), Some(&(4, 0)));
+    assert!(visited.windows(2).all(|w| w[0].1 <= w[1].1));
+    assert_eq!(visited.len(), 10);
+
+    // Whichever goal is cheapest wins, and the search stops there
+    let mut dijkstra = Dijkstra::new().neighbors(line).goals([0, 9]);
+    let paths = dijkstra.shortest_paths([4]).unwrap();
+    assert_eq!((paths.cost, paths.goals), (6, vec![0]));
+    assert!(dijkstra.distances([4]).len() < 10);
+
+    // And it can be run again from somewhere else
+    assert_eq!(dijkstra.cost([8]), Some(9));
+}
+
+const SLOPES: &str = "\
+#.#######
+#...#...#
+#.#.#.#.#
+#.#>..#.#
+#.#.#.#.#
+#...#...#
+#####v#.#
+#.......#
+#######.#
+";
+
+/// Tries every path cell by cell
+fn brute_force(
+    grid: &DefaultGrid<char>,
+    at: Coords,
+    end: Coords,
+    seen: &mut HashSet<Coords>,
+    slopes: bool,
+) -> Option<usize> {
+    if at == end {
+        return Some(0);
+    }
+
+    let mut best = None;
+    for &(delta, _) in &STEPS {
+        if let Some(next) = maze_step(grid, at, delta, slopes).filter(|n| !seen.contains(n)) {
+            seen.insert(next);
+            if let Some(rest) = brute_force(grid, next, end, seen, slopes) {
+                best = best.max(Some(rest + 1));
+            }
+            seen.remove(&next);
+        }
+    }
+    best
+}
+
+#[test]
+fn maze_junctions() {
+    let grid = Grid::from_chars(MAZE.replace(['S', 'G'], ".").as_str(), Sided::Four);
+    let graph = JunctionGraph::new(&grid, (0, 0), (4, 7), false).unwrap();
+
+    assert_eq!(graph.nodes[graph.start], (0, 0));
+    assert_eq!(graph.nodes[graph.end], (4, 7));
+    assert!(graph.nodes.contains(&(2, 7)));
+    assert!(graph.nodes.len() < MAZE.matches(['.', 'S', 'G']).count());
+    assert!(graph.edges.iter().flatten().all(|&(_, steps)| steps > 0));
+    assert_eq!(
+        graph.longest_path(),
+        brute_force(&grid, (0, 0), (4, 7), &mut HashSet::from([(0, 0)]), false)
+    );
+}
+
+#[test]
+fn maze_slopes() {
+    let grid: DefaultGrid<char> = Grid::from_chars(SLOPES, Sided::Four);
+    let (start, end) = ((0, 1), (8, 7));
+
+    for slopes in [true, false] {
+        let graph = JunctionGraph::new(&grid, start, end, slopes).unwrap();
+        let expected = brute_force(&grid, start, end, &mut HashSet::from([start]), slopes);
+
+        assert!(expected.is_some());
+        assert_eq!(graph.longest_path(), expected);
+    }
+
+    // The `>` junction can only be left eastwards, and `v` can't be climbed from below
+    let free = JunctionGraph::new(&grid, start, end, false).unwrap();
+    let sloped = JunctionGraph::new(&grid, start, end, true).unwrap();
+    let at = |graph: &JunctionGraph, coords| graph.nodes.iter().position(|&n| n == coords).unwrap();
+
+    assert_eq!(
+        sloped.edges[at(&sloped, (3, 3))],
+        vec![(at(&sloped, (3, 5)), 2)]
+    );
+    assert_eq!(free.edges[at(&free, (3, 3))].len(), 3);
+    assert_eq!(sloped.edges[at(&sloped, (7, 5))].len(), 1);
+    assert!(sloped.longest_path() <= free.longest_path());
+
+    let blocked: DefaultGrid<char> = Grid::from_chars("#.#\n#^#\n#.#", Sided::Four);
+    let graph = JunctionGraph::new(&blocked, (0, 1), (2, 1), true).unwrap();
+    assert_eq!(graph.longest_path(), None);
+}
+
+#[test]
+fn maze_last_junction() {
+    let grid: DefaultGrid<char> = Grid::from_chars(SLOPES, Sided::Four);
+    let (start, end) = ((0, 1), (8, 7));
+    let graph = JunctionGraph::new(&grid, start, end, false).unwrap();
+    let expected = brute_force(&grid, start, end, &mut HashSet::from([start]), false);
+
+    // (7, 7) is the only way into the end, and the search has other corridors from it to skip
+    let last = graph.last_junction().unwrap();
+    assert_eq!(graph.nodes[last], (7, 7));
+    assert!(graph.edges[last].iter().any(|&(j, _)| j != graph.end));
+
+    assert_eq!(graph.longest_with(Some(last)), expected);
+    assert_eq!(graph.longest_with(None), expected);
+
+    // Forcing a junction that does have other ways on cuts off every path through it
+    let forced = graph.nodes.iter().position(|&n| n == (3, 5)).unwrap();
+    assert!(graph.longest_with(Some(forced)) < expected);
+}
+
+#[test]
+fn maze_many_junctions() {
+    // A corridor with a dead end hanging off every other cell
+    let width = 141;
+    let teeth = (0..width)
+        .map(|x| if x % 2 == 0 { '.' } else { '#' })
+        .collect::<String>();
+    let comb = format!("{}\n{}", ".".repeat(width), teeth);
+    let grid: DefaultGrid<char> = Grid::from_chars(&comb, Sided::Four);
+
+    let graph = JunctionGraph::new(&grid, (0, 0), (0, width - 1), false).unwrap();
+    assert!(graph.nodes.len() > 64);
+    assert_eq!(graph.longest_path(), Some(width - 1));
+}
+
+#[test]
+fn maze_bad_ends() {
+    let grid: DefaultGrid<char> = Grid::from_chars(SLOPES, Sided::Four);
+
+    assert!(JunctionGraph::new(&grid, (0, 0), (8, 7), false).is_none());
+    assert!(JunctionGraph::new(&grid, (0, 1), (9, 7), false).is_none());
+}
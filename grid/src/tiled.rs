@@ -0,0 +1,95 @@
+use crate::cell::{Cell, DELTAS};
+use crate::grid::{Coords, Grid, Sided};
+use crate::sparse::SparseCoords;
+
+/// How coordinates past the edge of the base grid are treated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Wrap {
+    /// The grid repeats forever, coordinates keep growing and each copy is a tile
+    Tiled,
+    /// Leaving one edge comes back in at the opposite one, coordinates stay on the base grid
+    Torus,
+}
+
+/// A view that maps any signed coordinate onto a base grid with modular arithmetic
+#[derive(Debug)]
+pub struct TiledGrid<'g, T, E> {
+    pub grid: &'g Grid<T, E>,
+    pub wrap: Wrap,
+}
+
+impl<'g, T, E> TiledGrid<'g, T, E> {
+    pub fn new(grid: &'g Grid<T, E>, wrap: Wrap) -> Self {
+        assert!(grid.rows > 0 && grid.cols > 0, "can't tile an empty grid");
+        Self { grid, wrap }
+    }
+
+    /// Where `coords` falls on the base grid
+    pub fn base_coords(&self, coords: SparseCoords) -> Coords {
+        let (y, x) = coords;
+        (
+            y.rem_euclid(self.grid.rows as i64) as usize,
+            x.rem_euclid(self.grid.cols as i64) as usize,
+        )
+    }
+
+    /// Which copy of the base grid `coords` is in, the base grid being `(0, 0)`
+    pub fn tile(&self, coords: SparseCoords) -> SparseCoords {
+        let (y, x) = coords;
+        (
+            y.div_euclid(self.grid.rows as i64),
+            x.div_euclid(self.grid.cols as i64),
+        )
+    }
+
+    pub fn get_cell(&self, coords: SparseCoords) -> &'g Cell<T, E> {
+        &self.grid[self.base_coords(coords)]
+    }
+
+    pub fn get_neighbor(&self, coords: SparseCoords, offset: usize) -> SparseCoords {
+        let (dy, dx) = DELTAS[offset];
+        self.wrapped((coords.0 + dy as i64, coords.1 + dx as i64))
+    }
+
+    /// Coordinates around `coords` following the base grid's `n_neighbors`. With
+    /// [`Wrap::Tiled`] they carry on past the base grid into the neighbouring copies, while
+    /// [`Wrap::Torus`] wraps them back inside it.
+    pub fn neighbor_coords(&self, coords: SparseCoords) -> Vec<SparseCoords> {
+        let step = match self.grid.n_neighbors {
+            Sided::Four => 2,
+            Sided::Eight => 1,
+        };
+
+        (0..DELTAS.len())
+            .step_by(step)
+            .map(|offset| self.get_neighbor(coords, offset))
+            .collect()
+    }
+
+    pub fn neighbors(&self, coords: SparseCoords) -> Vec<(SparseCoords, &'g Cell<T, E>)> {
+        self.neighbor_coords(coords)
+            .into_iter()
+            .map(|n| (n, self.get_cell(n)))
+            .collect()
+    }
+
+    fn wrapped(&self, coords: SparseCoords) -> SparseCoords {
+        match self.wrap {
+            Wrap::Tiled => coords,
+            Wrap::Torus => {
+                let (y, x) = self.base_coords(coords);
+                (y as i64, x as i64)
+            }
+        }
+    }
+}
+
+impl<T, E> Grid<T, E> {
+    pub fn tiled(&self) -> TiledGrid<'_, T, E> {
+        TiledGrid::new(self, Wrap::Tiled)
+    }
+
+    pub fn torus(&self) -> TiledGrid<'_, T, E> {
+        TiledGrid::new(self, Wrap::Torus)
+    }
+}
@@ -0,0 +1,265 @@
+use serde::Serialize;
+use std::path::Path;
+use std::time::Duration;
+use tracing::info_span;
+
+use crate::config::Format;
+use crate::error::Result;
+use crate::load_puzzle;
+use crate::runner::{error_row, render_csv, render_table, DayError, DayResult};
+use crate::solution::{Parts, Runner, Samples};
+
+const HEADERS: [&str; 7] = ["Year", "Day", "Stage", "Min", "Median", "Mean", "Std Dev"];
+const CSV_HEADERS: [&str; 8] = [
+    "year",
+    "day",
+    "stage",
+    "min_ns",
+    "median_ns",
+    "mean_ns",
+    "stddev_ns",
+    "error",
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DayBench {
+    pub year: u32,
+    pub day: u32,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut nanos = samples
+            .iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        nanos.sort_by(f64::total_cmp);
+
+        let n = nanos.len().max(1) as f64;
+        let mean = nanos.iter().sum::<f64>() / n;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        let median = match nanos.len() {
+            0 => 0.0,
+            len if len % 2 == 0 => (nanos[len / 2 - 1] + nanos[len / 2]) / 2.0,
+            len => nanos[len / 2],
+        };
+
+        Self {
+            min_ns: nanos.first().copied().unwrap_or_default() as u64,
+            median_ns: median as u64,
+            mean_ns: mean as u64,
+            stddev_ns: variance.sqrt() as u64,
+        }
+    }
+
+    fn nanos(&self) -> [String; 4] {
+        [self.min_ns, self.median_ns, self.mean_ns, self.stddev_ns].map(|ns| ns.to_string())
+    }
+
+    fn row(&self) -> [String; 4] {
+        [self.min_ns, self.median_ns, self.mean_ns, self.stddev_ns]
+            .map(|ns| format!("{:?}", Duration::from_nanos(ns)))
+    }
+}
+
+pub fn bench_day(
+    year: u32,
+    day: u32,
+    solution: &dyn Runner,
+    input: Option<&Path>,
+    iterations: usize,
+    parts: Parts,
+) -> Result<DayBench> {
+    let _span = info_span!("day", year, day).entered();
+    let puzzle = load_puzzle(year, day, input)?;
+    let Samples {
+        parse,
+        part1,
+        part2,
+    } = solution.bench(&puzzle, iterations, parts)?;
+
+    Ok(DayBench {
+        year,
+        day,
+        iterations,
+        parse: Stats::new(&parse),
+        part1: parts.includes(1).then(|| Stats::new(&part1)),
+        part2: parts.includes(2).then(|| Stats::new(&part2)),
+    })
+}
+
+/// How a failed day shows up in the JSON output
+#[derive(Serialize)]
+struct Failure {
+    year: u32,
+    day: u32,
+    error: String,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum Entry<'a> {
+    Bench(&'a DayBench),
+    Failed(Failure),
+}
+
+pub fn print_benches(benches: &[DayResult<DayBench>], format: Format) {
+    match format {
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&entries(benches)).expect("Benches should serialize")
+        ),
+        Format::Csv => print!("{}", render_csv(CSV_HEADERS, &csv_rows(benches))),
+        Format::Text => print!("{}", render_table(HEADERS, &rows(benches))),
+    }
+}
+
+fn entries(benches: &[DayResult<DayBench>]) -> Vec<Entry<'_>> {
+    benches
+        .iter()
+        .map(|b| match b {
+            Ok(bench) => Entry::Bench(bench),
+            Err(DayError { year, day, error }) => Entry::Failed(Failure {
+                year: *year,
+                day: *day,
+                error: error.to_string(),
+            }),
+        })
+        .collect()
+}
+
+fn rows(benches: &[DayResult<DayBench>]) -> Vec<[String; 7]> {
+    benches
+        .iter()
+        .flat_map(|b| match b {
+            Ok(bench) => stages(bench, Stats::row),
+            Err(DayError { year, day, error }) => vec![error_row(*year, *day, error)],
+        })
+        .collect()
+}
+
+fn csv_rows(benches: &[DayResult<DayBench>]) -> Vec<[String; 8]> {
+    benches
+        .iter()
+        .flat_map(|b| match b {
+            Ok(bench) => stages(bench, Stats::nanos)
+                .into_iter()
+                .map(|[year, day, stage, min, median, mean, stddev]| {
+                    [year, day, stage, min, median, mean, stddev, String::new()]
+                })
+                .collect(),
+            Err(DayError { year, day, error }) => {
+                let mut row: [String; 8] = Default::default();
+                row[0] = year.to_string();
+                row[1] = day.to_string();
+                row[7] = error.to_string();
+                vec![row]
+            }
+        })
+        .collect()
+}
+
+fn stages(b: &DayBench, cells: fn(&Stats) -> [String; 4]) -> Vec<[String; 7]> {
+    [
+        ("Parse", Some(&b.parse)),
+        ("Part 1", b.part1.as_ref()),
+        ("Part 2", b.part2.as_ref()),
+    ]
+    .into_iter()
+    .filter_map(|(stage, stats)| Some((stage, stats?)))
+    .map(|(stage, stats)| {
+        let [min, median, mean, stddev] = cells(stats);
+        [
+            b.year.to_string(),
+            b.day.to_string(),
+            stage.to_string(),
+            min,
+            median,
+            mean,
+            stddev,
+        ]
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [5, 1, 3, 2, 4].map(Duration::from_nanos);
+        let stats = Stats::new(&samples);
+
+        assert_eq!(
+            stats,
+            Stats {
+                min_ns: 1,
+                median_ns: 3,
+                mean_ns: 3,
+                stddev_ns: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_stats_even() {
+        let samples = [10, 40, 20, 30].map(Duration::from_nanos);
+        let stats = Stats::new(&samples);
+
+        assert_eq!(stats.min_ns, 10);
+        assert_eq!(stats.median_ns, 25);
+        assert_eq!(stats.mean_ns, 25);
+        assert_eq!(stats.stddev_ns, 11);
+    }
+
+    #[test]
+    fn test_failed_day() {
+        let stats = Stats::new(&[Duration::from_nanos(5)]);
+        let bench = DayBench {
+            year: 2023,
+            day: 1,
+            iterations: 1,
+            parse: stats.clone(),
+            part1: Some(stats),
+            part2: None,
+        };
+        let failed = DayError::new(
+            2023,
+            2,
+            crate::error::AocError::Solution("bad input".to_string()),
+        );
+        let benches = [Ok(bench), Err(failed)];
+
+        let rows = rows(&benches);
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[2][2], "error");
+        assert_eq!(rows[2][3], "Solution failed: bad input");
+
+        let csv = render_csv(CSV_HEADERS, &csv_rows(&benches));
+        assert_eq!(
+            csv.lines().last(),
+            Some("2023,2,,,,,,Solution failed: bad input")
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        let stats = Stats::new(&[]);
+
+        assert_eq!(stats.min_ns, 0);
+        assert_eq!(stats.median_ns, 0);
+    }
+}
@@ -0,0 +1,392 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{self, Display};
+use std::fs::read_to_string;
+use std::path::PathBuf;
+
+use crate::config::{config, Format};
+use crate::error::{AocError, Result};
+use crate::runner::{render_csv, render_table, DayError, DayResult, DayRun};
+use crate::solution::Answer;
+
+#[derive(Debug, Default, Deserialize)]
+struct Expected {
+    part1: Option<toml::Value>,
+    part2: Option<toml::Value>,
+}
+
+/// Known good answers for a year, keyed by day
+pub type Answers = BTreeMap<u32, [Option<String>; 2]>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String, actual: Answer },
+    Missing,
+}
+
+#[derive(Debug, Clone)]
+pub struct CheckResult {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub status: Status,
+}
+
+/// `yYY.toml` in the configured `answers_dir`
+pub fn answers_path(year: u32) -> PathBuf {
+    config().answers_dir.join(format!("y{}.toml", year % 2000))
+}
+
+pub fn load_answers(year: u32) -> Result<Answers> {
+    let path = answers_path(year);
+
+    match read_to_string(&path) {
+        Ok(contents) => {
+            parse_answers(&contents).map_err(|message| AocError::InvalidAnswers { path, message })
+        }
+        Err(_) => Ok(Answers::new()),
+    }
+}
+
+fn parse_answers(input: &str) -> std::result::Result<Answers, String> {
+    parse_expected(input)?
+        .into_iter()
+        .map(|(day, answers)| {
+            let day = day
+                .parse()
+                .map_err(|_| format!("`{}` is not a day number", day))?;
+
+            Ok((day, answers))
+        })
+        .collect()
+}
+
+/// Parses `[key]` tables of `part1`/`part2` answers, shared with the example harness
+pub fn parse_expected(
+    input: &str,
+) -> std::result::Result<BTreeMap<String, [Option<String>; 2]>, String> {
+    let tables: HashMap<String, Expected> = toml::from_str(input).map_err(|e| e.to_string())?;
+
+    Ok(tables
+        .into_iter()
+        .map(|(key, Expected { part1, part2 })| {
+            (
+                key,
+                [part1.map(value_to_string), part2.map(value_to_string)],
+            )
+        })
+        .collect())
+}
+
+fn value_to_string(value: toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s,
+        other => other.to_string(),
+    }
+}
+
+/// Days that failed to run are skipped, [`print_report`] lists them separately
+pub fn check(runs: &[DayResult<DayRun>]) -> Result<Vec<CheckResult>> {
+    let mut answers: HashMap<u32, Answers> = HashMap::new();
+    let mut results = vec![];
+
+    for DayRun { year, day, run } in runs.iter().flatten() {
+        if !answers.contains_key(year) {
+            answers.insert(*year, load_answers(*year)?);
+        }
+
+        let expected = answers[year].get(day).cloned().unwrap_or_default();
+
+        for (part, (expected, result)) in expected.into_iter().zip(run.parts()).enumerate() {
+            if let Some(Ok(result)) = result {
+                results.push(CheckResult {
+                    year: *year,
+                    day: *day,
+                    part: part as u8 + 1,
+                    status: status(expected, &result.answer),
+                });
+            }
+        }
+    }
+
+    Ok(results)
+}
+
+pub fn status(expected: Option<String>, actual: &Answer) -> Status {
+    match (expected, actual) {
+        (None, _) => Status::Missing,
+        (Some(e), Answer::Solved(a)) if e == *a => Status::Pass,
+        (Some(expected), actual) => Status::Fail {
+            expected,
+            actual: actual.clone(),
+        },
+    }
+}
+
+/// One row per checked part, or per part or day that failed to run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CheckRecord {
+    pub year: u32,
+    pub day: u32,
+    pub part: Option<u8>,
+    pub status: &'static str,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+    pub error: Option<String>,
+}
+
+const HEADERS: [&str; 7] = [
+    "Year", "Day", "Part", "Status", "Expected", "Actual", "Error",
+];
+const CSV_HEADERS: [&str; 7] = [
+    "year", "day", "part", "status", "expected", "actual", "error",
+];
+
+fn records(results: &[CheckResult], runs: &[DayResult<DayRun>]) -> Vec<CheckRecord> {
+    let error = |year: u32, day: u32, part: Option<u8>, error: &AocError| CheckRecord {
+        year,
+        day,
+        part,
+        status: "error",
+        expected: None,
+        actual: None,
+        error: Some(error.to_string()),
+    };
+
+    let checked = results.iter().map(
+        |CheckResult {
+             year,
+             day,
+             part,
+             status,
+         }| {
+            let (status, expected, actual) = match status {
+                Status::Pass => ("pass", None, None),
+                Status::Fail { expected, actual } => {
+                    ("fail", Some(expected.clone()), Some(actual.to_string()))
+                }
+                Status::Missing => ("missing", None, None),
+            };
+
+            CheckRecord {
+                year: *year,
+                day: *day,
+                part: Some(*part),
+                status,
+                expected,
+                actual,
+                error: None,
+            }
+        },
+    );
+    let errored = runs.iter().flat_map(|result| match result {
+        Ok(DayRun { year, day, run }) => run
+            .parts()
+            .into_iter()
+            .enumerate()
+            .filter_map(|(part, result)| {
+                Some(error(
+                    *year,
+                    *day,
+                    Some(part as u8 + 1),
+                    result?.as_ref().err()?,
+                ))
+            })
+            .collect(),
+        Err(DayError {
+            year,
+            day,
+            error: e,
+        }) => vec![error(*year, *day, None, e)],
+    });
+
+    let mut records = checked.chain(errored).collect::<Vec<_>>();
+    records.sort_by_key(|r| (r.year, r.day, r.part));
+    records
+}
+
+fn rows(records: &[CheckRecord]) -> Vec<[String; 7]> {
+    records
+        .iter()
+        .map(|r| {
+            [
+                r.year.to_string(),
+                r.day.to_string(),
+                r.part.map(|p| p.to_string()).unwrap_or_default(),
+                r.status.to_string(),
+                r.expected.clone().unwrap_or_default(),
+                r.actual.clone().unwrap_or_default(),
+                r.error.clone().unwrap_or_default(),
+            ]
+        })
+        .collect()
+}
+
+/// Prints every result and every day or part that failed to run in `format`, failing if any
+/// answer did not match or had nothing to check it against
+pub fn print_report(
+    results: &[CheckResult],
+    runs: &[DayResult<DayRun>],
+    format: Format,
+) -> Result<()> {
+    let records = records(results, runs);
+    let count = |status| records.iter().filter(|r| r.status == status).count();
+    let (failed, missing, errors) = (count("fail"), count("missing"), count("error"));
+
+    match format {
+        Format::Text => println!(
+            "{}{} passed, {} failed, {} missing{}",
+            render_table(HEADERS, &rows(&records)),
+            count("pass"),
+            failed,
+            missing,
+            match errors {
+                0 => String::new(),
+                errors => format!(", {} errored", errors),
+            }
+        ),
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&records).expect("Records should serialize")
+        ),
+        Format::Csv => print!("{}", render_csv(CSV_HEADERS, &rows(&records))),
+    }
+
+    match (failed, missing) {
+        (0, 0) => Ok(()),
+        (failed, missing) => Err(AocError::CheckFailed { failed, missing }),
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail { expected, actual } => {
+                write!(f, "FAIL (expected {}, got {})", expected, actual)
+            }
+            Status::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_ANSWERS: &str = "\
+[1]
+part1 = 24000
+part2 = \"45000\"
+
+[2]
+part1 = 15
+";
+
+    #[test]
+    fn test_parse() {
+        let answers = parse_answers(TEST_ANSWERS).unwrap();
+
+        assert_eq!(
+            answers.get(&1),
+            Some(&[Some("24000".to_string()), Some("45000".to_string())])
+        );
+        assert_eq!(answers.get(&2), Some(&[Some("15".to_string()), None]));
+        assert!(parse_answers("[one]\npart1 = 1").is_err());
+    }
+
+    #[test]
+    fn test_status() {
+        let answers = parse_answers(TEST_ANSWERS).unwrap();
+        let [part1, part2] = answers.get(&1).cloned().unwrap();
+
+        assert_eq!(status(part1, &24000.into()), Status::Pass);
+        assert_eq!(
+            status(part2.clone(), &45001.into()),
+            Status::Fail {
+                expected: "45000".to_string(),
+                actual: 45001.into()
+            }
+        );
+        assert_eq!(
+            status(part2, &Answer::Unsolved),
+            Status::Fail {
+                expected: "45000".to_string(),
+                actual: Answer::Unsolved
+            }
+        );
+        assert_eq!(status(None, &15.into()), Status::Missing);
+    }
+
+    #[test]
+    fn test_records() {
+        use crate::solution::{PartResult, Run};
+        use std::time::Duration;
+
+        let part = |answer: u32| {
+            Some(Ok(PartResult {
+                answer: answer.into(),
+                elapsed: Duration::ZERO,
+            }))
+        };
+        let runs = [
+            Err(DayError::new(
+                2022,
+                3,
+                AocError::Solution("bad".to_string()),
+            )),
+            Ok(DayRun {
+                year: 2022,
+                day: 1,
+                run: Run {
+                    parse: Duration::ZERO,
+                    part1: part(24000),
+                    part2: Some(Err(AocError::Solution("no elves".to_string()))),
+                },
+            }),
+            Ok(DayRun {
+                year: 2022,
+                day: 2,
+                run: Run {
+                    parse: Duration::ZERO,
+                    part1: part(16),
+                    part2: None,
+                },
+            }),
+        ];
+        let results = [
+            CheckResult {
+                year: 2022,
+                day: 1,
+                part: 1,
+                status: Status::Pass,
+            },
+            CheckResult {
+                year: 2022,
+                day: 2,
+                part: 1,
+                status: status(Some("15".to_string()), &16.into()),
+            },
+        ];
+
+        let records = records(&results, &runs);
+        assert_eq!(
+            records.iter().map(|r| r.status).collect::<Vec<_>>(),
+            ["pass", "error", "fail", "error"]
+        );
+        assert_eq!(records[2].expected.as_deref(), Some("15"));
+        assert_eq!(records[2].actual.as_deref(), Some("16"));
+        assert_eq!(records[3].part, None);
+        assert_eq!(
+            render_csv(CSV_HEADERS, &rows(&records)),
+            "\
+year,day,part,status,expected,actual,error
+2022,1,1,pass,,,
+2022,1,2,error,,,Solution failed: no elves
+2022,2,1,fail,15,16,
+2022,3,,error,,,Solution failed: bad
+"
+        );
+    }
+}
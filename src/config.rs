@@ -0,0 +1,185 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Deserializer};
+use std::env;
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use tracing::level_filters::LevelFilter;
+
+use crate::error::{AocError, Result};
+use crate::fetch::DEFAULT_BASE_URL;
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// How run and benchmark results are printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+/// Runner defaults from `aoc.toml`, each of which can be overridden by an `AOC_*` variable
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub year: u32,
+    pub input_dir: PathBuf,
+    pub debug_dir: PathBuf,
+    pub example_dir: PathBuf,
+    pub answers_dir: PathBuf,
+    #[serde(deserialize_with = "level_filter")]
+    pub log_level: LevelFilter,
+    pub format: Format,
+    pub base_url: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            year: 2023,
+            input_dir: PathBuf::from("input"),
+            debug_dir: PathBuf::from("debug"),
+            example_dir: PathBuf::from("examples"),
+            answers_dir: PathBuf::from("answers"),
+            log_level: LevelFilter::WARN,
+            format: Format::Text,
+            base_url: DEFAULT_BASE_URL.to_string(),
+        }
+    }
+}
+
+impl Config {
+    /// Reads `path` if it exists, then applies the environment on top
+    pub fn load(path: &Path) -> Result<Self> {
+        let invalid = |message: String| AocError::InvalidConfig {
+            path: path.to_path_buf(),
+            message,
+        };
+
+        let config = match read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents).map_err(|e| invalid(e.to_string()))?,
+            Err(_) => Self::default(),
+        };
+
+        config
+            .with_overrides(|key| env::var(key).ok())
+            .map_err(invalid)
+    }
+
+    fn with_overrides(
+        mut self,
+        var: impl Fn(&str) -> Option<String>,
+    ) -> std::result::Result<Self, String> {
+        fn parse<T: std::str::FromStr>(key: &str, value: String) -> std::result::Result<T, String> {
+            value
+                .parse()
+                .map_err(|_| format!("{} has an invalid value `{}`", key, value))
+        }
+
+        if let Some(year) = var("AOC_YEAR") {
+            self.year = parse("AOC_YEAR", year)?;
+        }
+        if let Some(dir) = var("AOC_INPUT_DIR") {
+            self.input_dir = dir.into();
+        }
+        if let Some(dir) = var("AOC_DEBUG_DIR") {
+            self.debug_dir = dir.into();
+        }
+        if let Some(dir) = var("AOC_EXAMPLE_DIR") {
+            self.example_dir = dir.into();
+        }
+        if let Some(dir) = var("AOC_ANSWERS_DIR") {
+            self.answers_dir = dir.into();
+        }
+        if let Some(level) = var("AOC_LOG_LEVEL") {
+            self.log_level = parse("AOC_LOG_LEVEL", level)?;
+        }
+        if let Some(format) = var("AOC_FORMAT") {
+            self.format = Format::from_str(&format, true)
+                .map_err(|_| format!("AOC_FORMAT has an invalid value `{}`", format))?;
+        }
+        if let Some(url) = var("AOC_BASE_URL") {
+            self.base_url = url;
+        }
+
+        Ok(self)
+    }
+}
+
+fn level_filter<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<LevelFilter, D::Error> {
+    String::deserialize(deserializer)?
+        .parse()
+        .map_err(serde::de::Error::custom)
+}
+
+/// Loads `aoc.toml` the first time it is called
+pub fn init() -> Result<&'static Config> {
+    if let Some(config) = CONFIG.get() {
+        return Ok(config);
+    }
+
+    let config = Config::load(Path::new(CONFIG_FILE))?;
+    Ok(CONFIG.get_or_init(|| config))
+}
+
+/// The loaded config, or the defaults when [`init`] never ran
+pub fn config() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config: Config = toml::from_str(
+            "\
+year = 2022
+input_dir = \"inputs\"
+log_level = \"info\"
+format = \"json\"
+",
+        )
+        .unwrap();
+
+        assert_eq!(config.year, 2022);
+        assert_eq!(config.input_dir, PathBuf::from("inputs"));
+        assert_eq!(config.debug_dir, PathBuf::from("debug"));
+        assert_eq!(config.log_level, LevelFilter::INFO);
+        assert_eq!(config.format, Format::Json);
+
+        assert!(toml::from_str::<Config>("yaer = 2022").is_err());
+    }
+
+    #[test]
+    fn test_overrides() {
+        let config = Config::default()
+            .with_overrides(|key| match key {
+                "AOC_YEAR" => Some("2022".to_string()),
+                "AOC_EXAMPLE_DIR" => Some("samples".to_string()),
+                "AOC_FORMAT" => Some("CSV".to_string()),
+                "AOC_ANSWERS_DIR" => Some("known".to_string()),
+                _ => None,
+            })
+            .unwrap();
+
+        assert_eq!(config.year, 2022);
+        assert_eq!(config.example_dir, PathBuf::from("samples"));
+        assert_eq!(config.format, Format::Csv);
+        assert_eq!(config.answers_dir, PathBuf::from("known"));
+        assert_eq!(config.input_dir, PathBuf::from("input"));
+
+        let err = Config::default()
+            .with_overrides(|key| (key == "AOC_YEAR").then(|| "next".to_string()))
+            .unwrap_err();
+        assert_eq!(err, "AOC_YEAR has an invalid value `next`");
+    }
+}
@@ -0,0 +1,196 @@
+use std::fmt::{self, Display};
+use std::io;
+use std::num::ParseIntError;
+use std::path::PathBuf;
+
+pub type Result<T> = std::result::Result<T, AocError>;
+
+#[derive(Debug)]
+pub enum AocError {
+    MissingInput {
+        path: PathBuf,
+    },
+    UnknownYear {
+        year: u32,
+        registered: Vec<u32>,
+    },
+    UnknownDay {
+        year: u32,
+        day: u32,
+        registered: Vec<u32>,
+    },
+    FileExists {
+        path: PathBuf,
+    },
+    InvalidAnswers {
+        path: PathBuf,
+        message: String,
+    },
+    InvalidConfig {
+        path: PathBuf,
+        message: String,
+    },
+    History {
+        path: PathBuf,
+        message: String,
+    },
+    CheckFailed {
+        failed: usize,
+        missing: usize,
+    },
+    MissingSession,
+    Request {
+        url: String,
+        message: String,
+    },
+    AlreadySubmitted {
+        answer: String,
+        outcome: String,
+    },
+    RateLimited {
+        seconds: u64,
+    },
+    Solution(String),
+    Io(io::Error),
+}
+
+impl AocError {
+    /// Never 2, which clap exits with on a bad command line
+    pub fn exit_code(&self) -> i32 {
+        use AocError::*;
+
+        match self {
+            CheckFailed { .. } => 1,
+            MissingInput { .. } => 11,
+            UnknownYear { .. } | UnknownDay { .. } => 3,
+            FileExists { .. } => 4,
+            InvalidAnswers { .. } | InvalidConfig { .. } | History { .. } => 5,
+            Solution(_) => 6,
+            Io(_) => 7,
+            MissingSession => 8,
+            Request { .. } => 9,
+            AlreadySubmitted { .. } | RateLimited { .. } => 10,
+        }
+    }
+
+    /// The error with the highest exit code, so a run over many days exits once at the end
+    pub fn worst(errors: impl IntoIterator<Item = Self>) -> Result<()> {
+        errors
+            .into_iter()
+            .max_by_key(AocError::exit_code)
+            .map_or(Ok(()), Err)
+    }
+}
+
+fn join(items: &[u32]) -> String {
+    items
+        .iter()
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use AocError::*;
+
+        match self {
+            MissingInput { path } => {
+                write!(f, "Missing puzzle input, expected it at {}", path.display())
+            }
+            UnknownYear { year, registered } => write!(
+                f,
+                "Year {} has no solutions, registered years: {}",
+                year,
+                join(registered)
+            ),
+            UnknownDay {
+                year,
+                day,
+                registered,
+            } => write!(
+                f,
+                "Day {} is not registered for {}, registered days: {}",
+                day,
+                year,
+                join(registered)
+            ),
+            FileExists { path } => write!(f, "{} already exists", path.display()),
+            InvalidAnswers { path, message } => {
+                write!(f, "Could not read {}: {}", path.display(), message)
+            }
+            InvalidConfig { path, message } => {
+                write!(f, "Invalid config in {}: {}", path.display(), message)
+            }
+            History { path, message } => write!(
+                f,
+                "Could not read submission history {}: {}",
+                path.display(),
+                message
+            ),
+            CheckFailed { failed, missing } => write!(
+                f,
+                "{} answer(s) did not match, {} had no known answer",
+                failed, missing
+            ),
+            MissingSession => write!(
+                f,
+                "No session token, set AOC_SESSION or write it to .session"
+            ),
+            Request { url, message } => write!(f, "Request to {} failed: {}", url, message),
+            AlreadySubmitted { answer, outcome } => {
+                write!(f, "Already submitted {}, it was the {}", answer, outcome)
+            }
+            RateLimited { seconds } => {
+                write!(f, "Submitted too recently, wait another {}s", seconds)
+            }
+            Solution(message) => write!(f, "Solution failed: {}", message),
+            Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+impl From<io::Error> for AocError {
+    fn from(value: io::Error) -> Self {
+        AocError::Io(value)
+    }
+}
+
+impl From<ParseIntError> for AocError {
+    fn from(value: ParseIntError) -> Self {
+        AocError::Solution(value.to_string())
+    }
+}
+
+impl From<&str> for AocError {
+    fn from(value: &str) -> Self {
+        AocError::Solution(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_worst() {
+        let missing = AocError::MissingInput {
+            path: PathBuf::from("input/y23/day1.txt"),
+        };
+
+        assert_ne!(missing.exit_code(), 2);
+        assert!(AocError::worst([]).is_ok());
+
+        let worst = AocError::worst([
+            AocError::CheckFailed {
+                failed: 1,
+                missing: 0,
+            },
+            missing,
+        ])
+        .unwrap_err();
+        assert_eq!(worst.exit_code(), 11);
+    }
+}
@@ -0,0 +1,52 @@
+use std::fs::read_to_string;
+use std::path::Path;
+
+use crate::check::{parse_expected, status, Status};
+use crate::solution::Parts;
+use crate::solutions;
+
+/// Runs `part` of the example at `path` through the registered day and compares it with the
+/// expected answer in the `answers.toml` next to it. A missing answer fails the test, so a
+/// newly scaffolded day fails until its example answers are filled in.
+pub fn check_example(year: u32, day: u32, path: &str, part: u8) {
+    let path = Path::new(path);
+    let name = path.file_stem().unwrap().to_string_lossy();
+    let answers = path.with_file_name("answers.toml");
+    let expected = read_to_string(&answers)
+        .map_err(|e| e.to_string())
+        .and_then(|contents| parse_expected(&contents))
+        .unwrap_or_else(|e| panic!("Invalid {}: {}", answers.display(), e))
+        .remove(name.as_ref())
+        .and_then(|expected| expected[part as usize - 1].clone())
+        .unwrap_or_else(|| {
+            panic!(
+                "{} {} part {} has no expected answer in {}",
+                year,
+                name,
+                part,
+                answers.display()
+            )
+        });
+
+    let input = read_to_string(path).unwrap();
+    let parts = if part == 1 { Parts::One } else { Parts::Two };
+    let run = solutions()[&(year, day)]
+        .run(&input, parts)
+        .unwrap_or_else(|e| panic!("{} failed: {}", name, e));
+    let result = if part == 1 { run.part1 } else { run.part2 }
+        .expect("The selected part always runs")
+        .unwrap_or_else(|e| panic!("{} part {} failed: {}", name, part, e));
+
+    let status = status(Some(expected), &result.answer);
+    assert_eq!(
+        status,
+        Status::Pass,
+        "{} {} part {}: {}",
+        year,
+        name,
+        part,
+        status
+    );
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
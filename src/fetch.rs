@@ -0,0 +1,163 @@
+use std::env;
+use std::fs::{self, read_to_string};
+use std::path::Path;
+
+use crate::config::config;
+use crate::error::{AocError, Result};
+use crate::puzzle_path;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const SESSION_FILE: &str = ".session";
+
+/// Session cookie from `AOC_SESSION`, falling back to the `.session` file
+pub fn session() -> Result<String> {
+    env::var("AOC_SESSION")
+        .ok()
+        .or_else(|| read_to_string(SESSION_FILE).ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .ok_or(AocError::MissingSession)
+}
+
+pub fn fetch_day(year: u32, day: u32) -> Result<()> {
+    let path = puzzle_path(year, day);
+
+    if is_cached(&path) {
+        println!("{} already exists, not fetching", path.display());
+        return Ok(());
+    }
+
+    fetch(&config().base_url, &session()?, year, day, &path)?;
+    println!("Fetched {}", path.display());
+
+    Ok(())
+}
+
+/// Empty files left behind by the scaffold don't count as cached
+fn is_cached(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|m| m.len() > 0)
+}
+
+/// Downloads the input to `path` unless it is already cached, returning whether it did
+pub fn fetch(base_url: &str, session: &str, year: u32, day: u32, path: &Path) -> Result<bool> {
+    if is_cached(path) {
+        return Ok(false);
+    }
+
+    let url = format!(
+        "{}/{}/day/{}/input",
+        base_url.trim_end_matches('/'),
+        year,
+        day
+    );
+    let request_error = |message: String| AocError::Request {
+        url: url.clone(),
+        message,
+    };
+    let input = ureq::get(&url)
+        .set("Cookie", &format!("session={}", session))
+        .call()
+        .map_err(|e| request_error(e.to_string()))?
+        .into_string()
+        .map_err(|e| request_error(e.to_string()))?;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, input)?;
+
+    Ok(true)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+    use std::thread::{self, JoinHandle};
+
+    /// Answers a single request with `status` and `body`, handing back the raw request
+    pub fn serve(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut content = vec![0; content_length];
+            std::io::Read::read_exact(&mut reader, &mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+
+            request
+        });
+
+        (url, handle)
+    }
+
+    pub fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch() {
+        let (url, server) = serve("200 OK", "1\n2\n3\n");
+        let path = temp_dir("fetch").join("y23/day6.txt");
+
+        assert!(fetch(&url, "abc", 2023, 6, &path).unwrap());
+        assert_eq!(read_to_string(&path).unwrap(), "1\n2\n3\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2023/day/6/input HTTP/1.1"));
+        assert!(request.contains("session=abc"));
+    }
+
+    #[test]
+    fn test_cached() {
+        let path = temp_dir("cached").join("day6.txt");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "cached").unwrap();
+
+        // Nothing is listening here, so a request would fail
+        assert!(!fetch("http://127.0.0.1:1", "abc", 2023, 6, &path).unwrap());
+        assert_eq!(read_to_string(&path).unwrap(), "cached");
+    }
+
+    #[test]
+    fn test_error() {
+        let (url, server) = serve("400 Bad Request", "Puzzle inputs differ by user.");
+        let path = temp_dir("error").join("day6.txt");
+
+        let err = fetch(&url, "bad", 2023, 6, &path).unwrap_err();
+        server.join().unwrap();
+
+        assert!(matches!(err, AocError::Request { .. }));
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,60 @@
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+use crate::error::Result;
+
+/// Installs the global subscriber, after which days only need the `tracing` macros. Every
+/// day and part runs in its own span, closing ones are logged with their timings.
+pub fn init(level: LevelFilter, file: Option<&Path>) -> Result<()> {
+    let builder = tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_target(false);
+
+    match file {
+        Some(path) => {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+
+            builder
+                .with_ansi(false)
+                .with_writer(Mutex::new(File::create(path)?))
+                .init();
+        }
+        None => builder.with_writer(io::stderr).init(),
+    }
+
+    Ok(())
+}
+
+/// `debug_dir/yYY/dayN.txt` for a single day, otherwise `debug_dir/aoc.txt`
+pub fn default_log_file(debug_dir: &Path, year: u32, day: Option<u32>) -> PathBuf {
+    match day {
+        Some(day) => debug_dir.join(format!("y{}/day{}.txt", year % 2000, day)),
+        None => debug_dir.join("aoc.txt"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_log_file() {
+        let dir = Path::new("debug");
+
+        assert_eq!(
+            default_log_file(dir, 2023, Some(20)),
+            PathBuf::from("debug/y23/day20.txt")
+        );
+        assert_eq!(
+            default_log_file(dir, 2023, None),
+            PathBuf::from("debug/aoc.txt")
+        );
+    }
+}
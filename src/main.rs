@@ -1,25 +1,105 @@
 #![allow(unused)]
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use paste::paste;
-use simplelog::{Config, LevelFilter, WriteLogger};
-use std::collections::HashMap;
 use std::env;
-use std::fs::{self, read_to_string, File};
-use std::path::PathBuf;
+use std::fs::read_to_string;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use tracing::level_filters::LevelFilter;
+
+use config::Format;
+use error::{AocError, Result};
+use runner::DayError;
+use solution::{Parts, Runner};
+
+mod bench;
+mod check;
+mod config;
+mod error;
+#[cfg(test)]
+mod examples;
+mod fetch;
+mod logging;
+mod runner;
+mod scaffold;
+mod solution;
+mod submit;
+mod utils;
 
 #[derive(Parser, Debug)]
+#[command(subcommand_negates_reqs = true)]
 struct Args {
-    /// Year to work with
-    #[arg(short, long, default_value = "2023")]
-    year: u32,
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Year to work with, defaults to the one in `aoc.toml`
+    #[arg(short, long, global = true)]
+    year: Option<u32>,
 
-    /// Add new file
+    /// Scaffold a new day, registering it and creating its input and example files
     #[arg(short, long)]
     new: bool,
 
+    /// Run every registered day of every year
+    #[arg(short, long, conflicts_with_all = ["year_all", "new"])]
+    all: bool,
+
+    /// Run every registered day of the selected year
+    #[arg(long, conflicts_with = "new")]
+    year_all: bool,
+
+    /// Compare answers against the known good ones in `answers_dir`, failing on any without one
+    #[arg(short, long, conflicts_with = "new")]
+    check: bool,
+
+    /// Benchmark parse and both parts over N iterations each
+    #[arg(short, long, value_name = "N", conflicts_with_all = ["new", "check"])]
+    bench: Option<usize>,
+
+    /// Output format for runs and benchmarks, overriding `aoc.toml`
+    #[arg(short, long, value_enum)]
+    format: Option<Format>,
+
+    /// Log verbosity (off, error, warn, info, debug, trace), overriding `aoc.toml`
+    #[arg(long, value_name = "LEVEL", global = true)]
+    log_level: Option<LevelFilter>,
+
+    /// Log to PATH instead of stderr, or to `debug/yYY/dayN.txt` when no path is given
+    #[arg(long, value_name = "PATH", global = true)]
+    log_file: Option<Option<PathBuf>>,
+
+    /// Which part(s) to run
+    #[arg(short, long, value_enum, default_value_t = Parts::Both, conflicts_with = "new")]
+    part: Parts,
+
+    /// Read the puzzle input from this file instead of `input/`, or `-` for stdin
+    #[arg(short, long, value_name = "PATH", conflicts_with_all = ["all", "year_all", "new", "check"])]
+    input: Option<PathBuf>,
+
     /// Target day
-    day: String,
+    #[arg(required_unless_present_any = ["all", "year_all"])]
+    day: Option<u32>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Download a day's input into `input/`, session from `AOC_SESSION` or `.session`
+    Fetch {
+        /// Target day
+        #[arg(short, long)]
+        day: u32,
+    },
+    /// Run a part and submit its answer, refusing answers already known to be wrong
+    Submit {
+        /// Target day
+        #[arg(short, long)]
+        day: u32,
+
+        /// Part to submit
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
 }
 
 macro_rules! aoc {
@@ -27,105 +107,164 @@ macro_rules! aoc {
 
     ( $( $year:literal => [ $($days:literal $(,)?)* ] $(,)? )* ) => {
         paste! {
-            $(
-                pub mod [<year$year>]  {
-                    $(
-                        pub mod [<day$days>];
-                    )*
-                }
-
-                fn [<y$year>](day: &str) {
-                    let solutions_map = ::std::collections::HashMap::from([
-                        $(
-                            (
-                                stringify!($days),
-                                {
-                                    use [<year$year>]::[<day$days>]::main as solution_main;
-                                    solution_main as fn()
-                                }
-                            )
-                        ),*
-                    ]);
-
-                    solutions_map.get(day).expect("Not in solutions map")();
-                }
-            )*
+            fn solutions() -> ::std::collections::BTreeMap<(u32, u32), Box<dyn Runner>> {
+                ::std::collections::BTreeMap::from([
+                    $($(
+                        (
+                            ($year, $days),
+                            Box::new([<year$year>]::[<day$days>]::Day) as Box<dyn Runner>
+                        ),
+                    )*)*
+                ])
+            }
         }
     };
 }
 
-aoc!(
-    2022 => [1, 2, 3],
-    2023 => [1, 2, 3, 4, 5, 6, 7, 8, 10, 17, 19, 20, 21, 23]
-);
-
-#[macro_export]
-macro_rules! time_it {
-    ($context:literal, $s:stmt) => {
-        let timer = std::time::Instant::now();
-        let res = { $s };
-        println!("{}: {:?}\n{:?}", $context, timer.elapsed(), res);
-    };
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+pub fn puzzle_path(year: u32, day: u32) -> PathBuf {
+    config::config()
+        .input_dir
+        .join(format!("y{}/day{}.txt", year % 2000, day))
 }
 
-pub fn debug_output<O: AsRef<str>>(output: O, year: usize, day: usize) {
-    let path = PathBuf::from_iter(["debug", &format!("y{year}/day{day}.txt")]);
-    fs::write(path, output.as_ref()).unwrap();
+pub fn get_puzzle(year: u32, day: u32) -> Result<String> {
+    let path = puzzle_path(year, day);
+    read_to_string(&path).map_err(|_| AocError::MissingInput { path })
 }
 
-pub fn debug_output_logger(year: u8, day: usize) {
-    let _ = WriteLogger::init(
-        LevelFilter::Debug,
-        Config::default(),
-        File::create(format!("debug/y{}/day{}.txt", year, day)).unwrap(),
-    );
+/// Reads `input` if given, `-` being stdin, otherwise the day's file in the input directory
+pub fn load_puzzle(year: u32, day: u32, input: Option<&Path>) -> Result<String> {
+    match input {
+        Some(path) if path == Path::new("-") => {
+            let mut puzzle = String::new();
+            io::stdin().read_to_string(&mut puzzle)?;
+            Ok(puzzle)
+        }
+        Some(path) => read_to_string(path).map_err(|_| AocError::MissingInput {
+            path: path.to_path_buf(),
+        }),
+        None => get_puzzle(year, day),
+    }
 }
 
-pub fn get_puzzle(year: &str, day: &str) -> String {
-    let path = PathBuf::from_iter(["input", &format!("y{year}/day{day}.txt")]);
-    read_to_string(path).expect("Not there")
+fn main() {
+    if let Err(e) = run(Args::parse()) {
+        eprintln!("Error: {}", e);
+        std::process::exit(e.exit_code());
+    }
 }
 
-const BOILERPLATE: fn(u8, u8) -> String = |year: u8, day: u8| {
-    format!(
-        r##"
-use crate::{{get_puzzle, time_it}};
-
-pub fn main() {{
-    // let puzzle = get_puzzle("{year}", "{day}");
-    //
-    // time_it!("Solution Pt 1", solution_pt1(&puzzle));
-    // time_it!("solution Pt 2", solution_pt2(&puzzle));
-}}
-"##
-    )
-};
+fn run(args: Args) -> Result<()> {
+    let config = config::init()?;
+    let year = match args.year.unwrap_or(config.year) {
+        y if y < 100 => y + 2000,
+        y => y,
+    };
+
+    let day = match args.command {
+        Some(Command::Fetch { day } | Command::Submit { day, .. }) => Some(day),
+        None => args.day,
+    };
+    let log_file = args.log_file.as_ref().map(|path| match path {
+        Some(path) => path.clone(),
+        None => logging::default_log_file(&config.debug_dir, year, day),
+    });
+    logging::init(
+        args.log_level.unwrap_or(config.log_level),
+        log_file.as_deref(),
+    )?;
 
-fn new_file(year: u8, day: u8) {
-    let path = PathBuf::from(format!("src/year20{}/day{}.rs", year, day));
-    if path.exists() {
-        panic!("Path already exists!")
+    if let Some(Command::Fetch { day }) = args.command {
+        return fetch::fetch_day(year, day);
     }
 
-    fs::write(path, BOILERPLATE(year, day)).unwrap();
-    println!("Created - Year 20{}, Day {}", year, day);
-}
+    if args.new {
+        return scaffold::new_day(year, args.day.expect("Day is required"));
+    }
 
-fn main() {
-    let args = Args::parse();
+    let solutions = solutions();
+
+    if !args.all && !solutions.keys().any(|&(y, _)| y == year) {
+        let mut registered = solutions.keys().map(|&(y, _)| y).collect::<Vec<_>>();
+        registered.dedup();
 
-    let year = match args.year {
-        2023 | 23 => y2023,
-        2022 | 22 => y2022,
-        y => panic!("Invalid year {}", y),
+        return Err(AocError::UnknownYear { year, registered });
+    }
+
+    let find = |day| {
+        solutions
+            .get(&(year, day))
+            .map(|solution| solution.as_ref())
+            .ok_or_else(|| AocError::UnknownDay {
+                year,
+                day,
+                registered: solutions
+                    .keys()
+                    .filter(|&&(y, _)| y == year)
+                    .map(|&(_, d)| d)
+                    .collect(),
+            })
     };
 
-    if args.new {
-        new_file((args.year % 2000) as u8, args.day.parse().unwrap());
-        return;
+    if let Some(Command::Submit { day, part }) = args.command {
+        return submit::submit_day(year, day, part, find(day)?);
     }
 
-    println!("Running Day: {}, Year: {}", &args.day, args.year);
+    let selected = if args.all || args.year_all {
+        solutions
+            .iter()
+            .filter(|((y, _), _)| args.all || *y == year)
+            .map(|(&(y, d), solution)| (y, d, solution.as_ref()))
+            .collect::<Vec<_>>()
+    } else {
+        let day = args.day.expect("Day is required");
+        vec![(year, day, find(day)?)]
+    };
+
+    let format = args.format.unwrap_or(config.format);
+
+    if let Some(iterations) = args.bench {
+        let benches = selected
+            .into_iter()
+            .map(|(y, d, solution)| {
+                bench::bench_day(y, d, solution, args.input.as_deref(), iterations, args.part)
+                    .map_err(|error| DayError::new(y, d, error))
+            })
+            .collect::<Vec<_>>();
+
+        bench::print_benches(&benches, format);
+        return AocError::worst(benches.into_iter().filter_map(|b| b.err().map(|e| e.error)));
+    }
 
-    year(&args.day);
+    let runs = selected
+        .into_iter()
+        .map(|(y, d, solution)| {
+            runner::run_day(y, d, solution, args.input.as_deref(), args.part)
+                .map_err(|error| DayError::new(y, d, error))
+        })
+        .collect::<Vec<_>>();
+
+    let report = if args.check {
+        check::print_report(&check::check(&runs)?, &runs, format)
+    } else if format == Format::Text && !(args.all || args.year_all) {
+        if let Ok(run) = &runs[0] {
+            println!("Running Day: {}, Year: {}", run.day, run.year);
+            runner::print_day(run);
+        }
+        Ok(())
+    } else {
+        runner::print_runs(&runs, format);
+        Ok(())
+    };
+
+    AocError::worst(
+        runs.into_iter()
+            .flat_map(|r| match r {
+                Ok(day_run) => day_run.run.into_errors().collect(),
+                Err(e) => vec![e.error],
+            })
+            .chain(report.err()),
+    )
 }
@@ -0,0 +1,401 @@
+use serde::Serialize;
+use std::path::Path;
+use std::time::Duration;
+use tracing::info_span;
+
+use crate::config::Format;
+use crate::error::{AocError, Result};
+use crate::load_puzzle;
+use crate::solution::{Answer, PartResult, Parts, Run, Runner};
+
+#[derive(Debug)]
+pub struct DayRun {
+    pub year: u32,
+    pub day: u32,
+    pub run: Run,
+}
+
+/// A day that failed, reported alongside the others instead of stopping the run
+#[derive(Debug)]
+pub struct DayError {
+    pub year: u32,
+    pub day: u32,
+    pub error: AocError,
+}
+
+pub type DayResult<T> = std::result::Result<T, DayError>;
+
+const HEADERS: [&str; 7] = ["Year", "Day", "Parse", "Part 1", "Time", "Part 2", "Time"];
+const CSV_HEADERS: [&str; 7] = [
+    "year", "day", "part", "answer", "parse_ns", "solve_ns", "error",
+];
+
+pub fn run_day(
+    year: u32,
+    day: u32,
+    solution: &dyn Runner,
+    input: Option<&Path>,
+    parts: Parts,
+) -> Result<DayRun> {
+    let _span = info_span!("day", year, day).entered();
+    let puzzle = load_puzzle(year, day, input)?;
+
+    Ok(DayRun {
+        year,
+        day,
+        run: solution.run(&puzzle, parts)?,
+    })
+}
+
+pub fn print_day(day_run: &DayRun) {
+    let run = &day_run.run;
+
+    println!("Parse: {:?}", run.parse);
+    for (part, result) in run.parts().into_iter().enumerate() {
+        match result {
+            Some(Ok(PartResult { answer, elapsed })) => {
+                println!("Part {}: {:?}\n{}", part + 1, elapsed, answer)
+            }
+            Some(Err(error)) => println!("Part {}: error\n{}", part + 1, error),
+            None => {}
+        }
+    }
+}
+
+impl DayError {
+    pub fn new(year: u32, day: u32, error: AocError) -> Self {
+        Self { year, day, error }
+    }
+}
+
+/// One row per part that ran, for the machine readable formats. A day that failed gets a
+/// single row with no part and its `error`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PartRecord {
+    pub year: u32,
+    pub day: u32,
+    pub part: Option<u8>,
+    pub answer: Option<String>,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+    pub error: Option<String>,
+}
+
+pub fn print_runs(runs: &[DayResult<DayRun>], format: Format) {
+    match format {
+        Format::Text => print!("{}", table(runs)),
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&records(runs)).expect("Records should serialize")
+        ),
+        Format::Csv => print!("{}", csv(&records(runs))),
+    }
+}
+
+fn records(runs: &[DayResult<DayRun>]) -> Vec<PartRecord> {
+    runs.iter()
+        .flat_map(|result| -> Vec<PartRecord> {
+            let DayRun { year, day, run } = match result {
+                Ok(day_run) => day_run,
+                Err(DayError { year, day, error }) => {
+                    return vec![PartRecord {
+                        year: *year,
+                        day: *day,
+                        part: None,
+                        answer: None,
+                        parse_ns: 0,
+                        solve_ns: 0,
+                        error: Some(error.to_string()),
+                    }]
+                }
+            };
+
+            run.parts()
+                .into_iter()
+                .enumerate()
+                .filter_map(|(part, result)| {
+                    let record = PartRecord {
+                        year: *year,
+                        day: *day,
+                        part: Some(part as u8 + 1),
+                        answer: None,
+                        parse_ns: run.parse.as_nanos() as u64,
+                        solve_ns: 0,
+                        error: None,
+                    };
+
+                    Some(match result? {
+                        Ok(PartResult { answer, elapsed }) => PartRecord {
+                            answer: match answer {
+                                Answer::Solved(answer) => Some(answer.clone()),
+                                Answer::Unsolved => None,
+                            },
+                            solve_ns: elapsed.as_nanos() as u64,
+                            ..record
+                        },
+                        Err(error) => PartRecord {
+                            error: Some(error.to_string()),
+                            ..record
+                        },
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn csv(records: &[PartRecord]) -> String {
+    let rows = records
+        .iter()
+        .map(|r| {
+            [
+                r.year.to_string(),
+                r.day.to_string(),
+                r.part.map(|p| p.to_string()).unwrap_or_default(),
+                r.answer.clone().unwrap_or_default(),
+                r.parse_ns.to_string(),
+                r.solve_ns.to_string(),
+                r.error.clone().unwrap_or_default(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    render_csv(CSV_HEADERS, &rows)
+}
+
+fn table(runs: &[DayResult<DayRun>]) -> String {
+    let rows: Vec<[String; 7]> = runs
+        .iter()
+        .map(|result| {
+            let DayRun { year, day, run } = match result {
+                Ok(day_run) => day_run,
+                Err(DayError { year, day, error }) => return error_row(*year, *day, error),
+            };
+            let [(part1, time1), (part2, time2)] = run.parts().map(|result| match result {
+                Some(Ok(PartResult { answer, elapsed })) => {
+                    (answer.to_string(), format!("{:?}", elapsed))
+                }
+                Some(Err(error)) => (format!("error: {}", error), String::new()),
+                None => (String::new(), String::new()),
+            });
+
+            [
+                year.to_string(),
+                day.to_string(),
+                format!("{:?}", run.parse),
+                part1,
+                time1,
+                part2,
+                time2,
+            ]
+        })
+        .collect();
+
+    let total: Duration = runs.iter().flatten().map(|r| r.run.elapsed()).sum();
+    let failed = runs
+        .iter()
+        .filter(|r| r.as_ref().map_or(true, |r| r.run.failed()))
+        .count();
+
+    format!(
+        "{}{} days in {:?}{}\n",
+        render_table(HEADERS, &rows),
+        runs.len() - failed,
+        total,
+        match failed {
+            0 => String::new(),
+            failed => format!(", {} failed", failed),
+        }
+    )
+}
+
+/// Year and day followed by the error in the first column that would have held a result
+pub fn error_row<const N: usize>(year: u32, day: u32, error: &AocError) -> [String; N] {
+    let mut row: [String; N] = std::array::from_fn(|_| String::new());
+    row[0] = year.to_string();
+    row[1] = day.to_string();
+    row[2] = "error".to_string();
+    row[3] = error.to_string();
+    row
+}
+
+pub fn render_table<const N: usize>(headers: [&str; N], rows: &[[String; N]]) -> String {
+    let mut widths = headers.map(|h| h.chars().count());
+    for row in rows.iter() {
+        for (width, col) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(col.chars().count());
+        }
+    }
+
+    let line = |row: &[String]| {
+        row.iter()
+            .zip(widths.iter())
+            .map(|(col, width)| format!(" {:<width$} ", col, width = width))
+            .collect::<Vec<_>>()
+            .join("|")
+            .trim_end()
+            .to_string()
+    };
+
+    let header = headers.map(String::from);
+    let divider = widths
+        .iter()
+        .map(|w| "-".repeat(w + 2))
+        .collect::<Vec<_>>()
+        .join("+");
+
+    let mut output = vec![line(&header), divider.clone()];
+    output.extend(rows.iter().map(|row| line(row)));
+    output.push(divider);
+    output.push(String::new());
+
+    output.join("\n")
+}
+
+pub fn render_csv<const N: usize>(headers: [&str; N], rows: &[[String; N]]) -> String {
+    let line = |row: &[String]| {
+        row.iter()
+            .map(|field| {
+                if field.contains([',', '"', '\n']) {
+                    format!("\"{}\"", field.replace('"', "\"\""))
+                } else {
+                    field.clone()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+
+    let mut output = vec![line(&headers.map(String::from))];
+    output.extend(rows.iter().map(|row| line(row)));
+    output.push(String::new());
+
+    output.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day_run(day: u32, part1: &str) -> DayRun {
+        DayRun {
+            year: 2023,
+            day,
+            run: Run {
+                parse: Duration::from_micros(1),
+                part1: Some(Ok(PartResult {
+                    answer: Answer::Solved(part1.to_string()),
+                    elapsed: Duration::from_micros(2),
+                })),
+                part2: Some(Ok(PartResult {
+                    answer: Answer::Unsolved,
+                    elapsed: Duration::from_micros(3),
+                })),
+            },
+        }
+    }
+
+    #[test]
+    fn test_table() {
+        let table = table(&[Ok(day_run(1, "12345")), Ok(day_run(10, "1"))]);
+        let lines = table.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 6);
+        assert_eq!(
+            lines[0],
+            " Year | Day | Parse | Part 1 | Time | Part 2 | Time"
+        );
+        assert_eq!(
+            lines[2],
+            " 2023 | 1   | 1µs   | 12345  | 2µs  | -      | 3µs"
+        );
+        assert_eq!(
+            lines[3],
+            " 2023 | 10  | 1µs   | 1      | 2µs  | -      | 3µs"
+        );
+        assert_eq!(lines[5], "2 days in 12µs");
+    }
+
+    #[test]
+    fn test_records() {
+        let mut run = day_run(1, "12345");
+        run.run.part2 = None;
+        let records = records(&[Ok(run), Ok(day_run(10, "1"))]);
+
+        assert_eq!(records.len(), 3);
+        assert_eq!(
+            records[0],
+            PartRecord {
+                year: 2023,
+                day: 1,
+                part: Some(1),
+                answer: Some("12345".to_string()),
+                parse_ns: 1000,
+                solve_ns: 2000,
+                error: None,
+            }
+        );
+        assert_eq!(records[2].answer, None);
+        assert_eq!(
+            csv(&records),
+            "\
+year,day,part,answer,parse_ns,solve_ns,error
+2023,1,1,12345,1000,2000,
+2023,10,1,1,1000,2000,
+2023,10,2,,1000,3000,
+"
+        );
+    }
+
+    #[test]
+    fn test_failed_day() {
+        let failed = DayError::new(2023, 2, AocError::Solution("bad input".to_string()));
+        let runs = [Ok(day_run(1, "12345")), Err(failed), Ok(day_run(3, "1"))];
+
+        let table = table(&runs);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 7);
+        assert!(lines[3].starts_with(" 2023 | 2   | error | Solution failed: bad input"));
+        assert!(lines[4].starts_with(" 2023 | 3   |"));
+        assert_eq!(lines[6], "2 days in 12µs, 1 failed");
+
+        let records = records(&runs);
+        assert_eq!(records.len(), 5);
+        assert_eq!(records[2].part, None);
+        assert_eq!(
+            records[2].error.as_deref(),
+            Some("Solution failed: bad input")
+        );
+    }
+
+    #[test]
+    fn test_failed_part() {
+        let mut run = day_run(1, "12345");
+        run.run.part2 = Some(Err(AocError::Solution("no xn".to_string())));
+        let runs = [Ok(run)];
+
+        let table = table(&runs);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines[2],
+            " 2023 | 1   | 1µs   | 12345  | 2µs  | error: Solution failed: no xn |"
+        );
+        assert_eq!(lines[4], "0 days in 3µs, 1 failed");
+
+        let records = records(&runs);
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].answer.as_deref(), Some("12345"));
+        assert_eq!(records[1].part, Some(2));
+        assert_eq!(records[1].error.as_deref(), Some("Solution failed: no xn"));
+    }
+
+    #[test]
+    fn test_csv_quoting() {
+        let rows = [["a,b".to_string(), "say \"hi\"".to_string()]];
+
+        assert_eq!(
+            render_csv(["x", "y"], &rows),
+            "x,y\n\"a,b\",\"say \"\"hi\"\"\"\n"
+        );
+    }
+}
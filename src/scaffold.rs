@@ -0,0 +1,150 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::config;
+use crate::error::{AocError, Result};
+
+const BOILERPLATE: fn(u32, u32, &str) -> String = |year: u32, day: u32, example: &str| {
+    format!(
+        r##"// https://adventofcode.com/20{year}/day/{day}
+
+use crate::error::Result;
+use crate::solution::{{Answer, Solution}};
+
+pub struct Day;
+
+impl Solution for Day {{
+    type Input<'i> = &'i str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {{
+        Ok(input)
+    }}
+
+    // fn part1(input: &Self::Input<'_>) -> Result<Answer> {{
+    //     Ok(solution_pt1(input).into())
+    // }}
+    //
+    // fn part2(input: &Self::Input<'_>) -> Result<Answer> {{
+    //     Ok(solution_pt2(input).into())
+    // }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    const TEST: &str = include_str!("{example}");
+
+    #[test]
+    #[ignore = "Fill in the example's part 1 answer"]
+    fn test_pt1() {{
+        let input = Day::parse(TEST).unwrap();
+
+        assert_eq!(Day::part1(&input).unwrap(), Answer::from(""));
+    }}
+
+    #[test]
+    #[ignore = "Fill in the example's part 2 answer"]
+    fn test_pt2() {{
+        let input = Day::parse(TEST).unwrap();
+
+        assert_eq!(Day::part2(&input).unwrap(), Answer::from(""));
+    }}
+}}
+"##
+    )
+};
+
+pub fn new_day(year: u32, day: u32) -> Result<()> {
+    let short = year % 2000;
+    let path = PathBuf::from(format!("src/year20{}/day{}.rs", short, day));
+    if path.exists() {
+        return Err(AocError::FileExists { path });
+    }
+
+    let config = config();
+    let file = format!("y{}/day{}.txt", short, day);
+    // `include_str!` paths are relative to the new file in `src/yearYYYY/`
+    let example = match &config.example_dir {
+        dir if dir.is_absolute() => dir.join(&file),
+        dir => Path::new("../..").join(dir).join(&file),
+    };
+
+    create_file(&path, &BOILERPLATE(short, day, &example.to_string_lossy()))?;
+
+    for dir in [&config.input_dir, &config.example_dir] {
+        let path = dir.join(&file);
+
+        if !path.exists() {
+            create_file(&path, "")?;
+        }
+    }
+
+    add_answers_stub(
+        &config.example_dir.join(format!("y{}/answers.toml", short)),
+        day,
+    )?;
+
+    println!("Created - Year 20{}, Day {}", short, day);
+
+    Ok(())
+}
+
+/// An empty `[dayN]` entry makes the day's generated example test fail until its answers
+/// are filled in
+fn add_answers_stub(path: &Path, day: u32) -> Result<()> {
+    let contents = fs::read_to_string(path).unwrap_or_default();
+    let header = format!("[day{}]", day);
+
+    if contents.lines().any(|line| line.trim() == header) {
+        return Ok(());
+    }
+
+    let separator = match contents.as_str() {
+        "" => "",
+        c if c.ends_with('\n') => "\n",
+        _ => "\n\n",
+    };
+    create_file(
+        path,
+        &format!(
+            "{}{}{}\n# part1 = \n# part2 = \n",
+            contents, separator, header
+        ),
+    )
+}
+
+fn create_file(path: &Path, contents: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    Ok(fs::write(path, contents)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check::parse_expected;
+    use crate::fetch::tests::temp_dir;
+
+    #[test]
+    fn test_answers_stub() {
+        let path = temp_dir("answers").join("y23/answers.toml");
+
+        add_answers_stub(&path, 24).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "[day24]\n# part1 = \n# part2 = \n"
+        );
+
+        fs::write(&path, "[day1]\npart1 = 1\n").unwrap();
+        add_answers_stub(&path, 24).unwrap();
+        add_answers_stub(&path, 24).unwrap();
+        add_answers_stub(&path, 1).unwrap();
+
+        let expected = parse_expected(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(expected.len(), 2);
+        assert_eq!(expected["day24"], [None, None]);
+    }
+}
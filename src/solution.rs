@@ -0,0 +1,297 @@
+use std::fmt::{self, Display};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use clap::ValueEnum;
+use tracing::info_span;
+
+use crate::error::{AocError, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Answer {
+    Solved(String),
+    #[default]
+    Unsolved,
+}
+
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// Parts that were not selected are `None`. Each part keeps its own error, so a failing
+/// part 2 doesn't hide part 1's answer.
+#[derive(Debug)]
+pub struct Run {
+    pub parse: Duration,
+    pub part1: Option<Result<PartResult>>,
+    pub part2: Option<Result<PartResult>>,
+}
+
+/// Which parts of a day to run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Parts {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    #[default]
+    Both,
+}
+
+/// Timings for each stage of a day, one entry per iteration
+#[derive(Debug, Clone, Default)]
+pub struct Samples {
+    pub parse: Vec<Duration>,
+    pub part1: Vec<Duration>,
+    pub part2: Vec<Duration>,
+}
+
+/// A single day of the puzzle. `parse` runs once and both parts share its output.
+pub trait Solution {
+    type Input<'i>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+
+    fn part1(_input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+/// Object safe side of [`Solution`] so every day can live in the same registry.
+pub trait Runner {
+    fn run(&self, input: &str, parts: Parts) -> Result<Run>;
+
+    /// Runs each stage once untimed as a warmup, then `iterations` more times on its own.
+    /// Parts that are not selected get no samples, and a stage failing its warmup fails here.
+    fn bench(&self, input: &str, iterations: usize, parts: Parts) -> Result<Samples>;
+}
+
+impl<S: Solution> Runner for S {
+    fn run(&self, input: &str, parts: Parts) -> Result<Run> {
+        let timer = Instant::now();
+        let parsed = info_span!("parse").in_scope(|| S::parse(input))?;
+        let parse = timer.elapsed();
+
+        let part1 = parts
+            .includes(1)
+            .then(|| PartResult::time(1, || S::part1(&parsed)));
+        let part2 = parts
+            .includes(2)
+            .then(|| PartResult::time(2, || S::part2(&parsed)));
+
+        Ok(Run {
+            parse,
+            part1,
+            part2,
+        })
+    }
+
+    fn bench(&self, input: &str, iterations: usize, parts: Parts) -> Result<Samples> {
+        let (parsed, parse) = sample(iterations, || S::parse(black_box(input)))?;
+        let part = |part, f: fn(&S::Input<'_>) -> Result<Answer>| -> Result<Vec<Duration>> {
+            if !parts.includes(part) {
+                return Ok(vec![]);
+            }
+            Ok(sample(iterations, || f(black_box(&parsed)))?.1)
+        };
+
+        Ok(Samples {
+            parse,
+            part1: part(1, S::part1)?,
+            part2: part(2, S::part2)?,
+        })
+    }
+}
+
+/// Times `f` after an untimed warmup, whose output is returned so a failure is never timed
+fn sample<T>(iterations: usize, mut f: impl FnMut() -> Result<T>) -> Result<(T, Vec<Duration>)> {
+    let warmup = f()?;
+
+    let samples = (0..iterations)
+        .map(|_| {
+            let timer = Instant::now();
+            black_box(f());
+            timer.elapsed()
+        })
+        .collect();
+
+    Ok((warmup, samples))
+}
+
+impl Parts {
+    pub fn includes(self, part: u8) -> bool {
+        matches!(
+            (self, part),
+            (Parts::Both, _) | (Parts::One, 1) | (Parts::Two, 2)
+        )
+    }
+}
+
+impl Run {
+    pub fn parts(&self) -> [Option<&Result<PartResult>>; 2] {
+        [self.part1.as_ref(), self.part2.as_ref()]
+    }
+
+    /// Parse time plus every part that ran
+    pub fn elapsed(&self) -> Duration {
+        self.parse
+            + self
+                .parts()
+                .into_iter()
+                .flatten()
+                .flatten()
+                .map(|p| p.elapsed)
+                .sum::<Duration>()
+    }
+
+    pub fn failed(&self) -> bool {
+        self.parts().into_iter().flatten().any(|p| p.is_err())
+    }
+
+    pub fn into_errors(self) -> impl Iterator<Item = AocError> {
+        [self.part1, self.part2]
+            .into_iter()
+            .flatten()
+            .filter_map(|p| p.err())
+    }
+}
+
+impl PartResult {
+    fn time(part: u8, f: impl FnOnce() -> Result<Answer>) -> Result<Self> {
+        let _span = info_span!("part", part).entered();
+        let timer = Instant::now();
+        let answer = f()?;
+
+        Ok(Self {
+            answer,
+            elapsed: timer.elapsed(),
+        })
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Solved(answer) => write!(f, "{}", answer),
+            Answer::Unsolved => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! impl_into_answer {
+    () => {};
+
+    ( $($type:ty $(,)?)* ) => {
+        $(
+            impl From<$type> for Answer {
+                fn from(value: $type) -> Self {
+                    Answer::Solved(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_into_answer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, String, &str);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Test;
+
+    impl Solution for Test {
+        type Input<'i> = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>> {
+            Ok(input
+                .lines()
+                .map(|l| l.parse())
+                .collect::<std::result::Result<_, _>>()?)
+        }
+
+        fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+            Ok(input.iter().sum::<u32>().into())
+        }
+    }
+
+    #[test]
+    fn test_bench() {
+        let samples = Test.bench("1\n2\n3", 5, Parts::Both).unwrap();
+
+        assert_eq!(samples.parse.len(), 5);
+        assert_eq!(samples.part1.len(), 5);
+        assert_eq!(samples.part2.len(), 5);
+    }
+
+    #[test]
+    fn test_run() {
+        let run = Test.run("1\n2\n3", Parts::Both).unwrap();
+
+        assert_eq!(
+            run.part1.unwrap().unwrap().answer,
+            Answer::Solved("6".to_string())
+        );
+        assert_eq!(run.part2.unwrap().unwrap().answer, Answer::Unsolved);
+    }
+
+    #[test]
+    fn test_run_part() {
+        let run = Test.run("1\n2\n3", Parts::Two).unwrap();
+        assert!(run.part1.is_none());
+        assert_eq!(run.part2.unwrap().unwrap().answer, Answer::Unsolved);
+
+        let samples = Test.bench("1\n2\n3", 5, Parts::One).unwrap();
+        assert_eq!(samples.part1.len(), 5);
+        assert!(samples.part2.is_empty());
+    }
+
+    struct Failing;
+
+    impl Solution for Failing {
+        type Input<'i> = &'i str;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>> {
+            Ok(input)
+        }
+
+        fn part2(_input: &Self::Input<'_>) -> Result<Answer> {
+            Err("no answer".into())
+        }
+    }
+
+    #[test]
+    fn test_bench_error() {
+        assert!(Test.bench("1\ntwo\n3", 5, Parts::Both).is_err());
+        assert!(Failing.bench("", 5, Parts::Both).is_err());
+
+        let samples = Failing.bench("", 5, Parts::One).unwrap();
+        assert_eq!(samples.part1.len(), 5);
+    }
+
+    #[test]
+    fn test_run_error() {
+        let run = Failing.run("", Parts::Both).unwrap();
+
+        assert!(run.failed());
+        assert_eq!(
+            run.part1.as_ref().unwrap().as_ref().unwrap().answer,
+            Answer::Unsolved
+        );
+        assert!(run.part2.as_ref().unwrap().is_err());
+        assert_eq!(run.into_errors().count(), 1);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Test.run("1\ntwo\n3", Parts::Both).unwrap_err();
+
+        assert!(matches!(err, AocError::Solution(_)));
+    }
+}
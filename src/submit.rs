@@ -0,0 +1,360 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use std::fs::{self, read_to_string};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::config::config;
+use crate::error::{AocError, Result};
+use crate::fetch::session;
+use crate::runner::run_day;
+use crate::solution::{Answer, Parts, Runner};
+
+const HISTORY_FILE: &str = "submissions.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    Wait,
+    AlreadySolved,
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Unix seconds the answer was submitted at
+    pub at: u64,
+    /// Unix seconds before which no other answer should be sent for this part
+    pub wait_until: Option<u64>,
+}
+
+/// Every attempt so far, kept in `submissions.json`
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct History(Vec<Submission>);
+
+impl History {
+    pub fn load(path: &Path) -> Result<Self> {
+        let error = |message: String| AocError::History {
+            path: path.to_path_buf(),
+            message,
+        };
+
+        match read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents).map_err(|e| error(e.to_string())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(error(e.to_string())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self).expect("History should serialize");
+        fs::write(path, json)?;
+        Ok(())
+    }
+
+    fn attempts(&self, year: u32, day: u32, part: u8) -> impl Iterator<Item = &Submission> {
+        self.0
+            .iter()
+            .filter(move |s| (s.year, s.day, s.part) == (year, day, part))
+    }
+
+    /// Refuses answers that are already known to be right, wrong or out of range, and
+    /// anything sent before the last wait is over
+    pub fn check(&self, year: u32, day: u32, part: u8, answer: &str, now: u64) -> Result<()> {
+        for previous in self.attempts(year, day, part) {
+            let known = match previous.outcome {
+                Outcome::Correct | Outcome::AlreadySolved => true,
+                Outcome::Wait | Outcome::Unknown => false,
+                Outcome::Wrong => previous.answer == answer,
+                Outcome::TooHigh => beyond(answer, &previous.answer, |a, p| a >= p),
+                Outcome::TooLow => beyond(answer, &previous.answer, |a, p| a <= p),
+            };
+
+            if known {
+                return Err(AocError::AlreadySubmitted {
+                    answer: previous.answer.clone(),
+                    outcome: previous.outcome.to_string(),
+                });
+            }
+        }
+
+        match self
+            .attempts(year, day, part)
+            .filter_map(|s| s.wait_until)
+            .max()
+        {
+            Some(until) if until > now => Err(AocError::RateLimited {
+                seconds: until - now,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn record(&mut self, submission: Submission) {
+        self.0.push(submission);
+    }
+}
+
+/// Whether `answer` falls on the wrong side of a previous too high/low `bound`
+fn beyond(answer: &str, bound: &str, f: fn(i128, i128) -> bool) -> bool {
+    match (answer.parse(), bound.parse()) {
+        (Ok(answer), Ok(bound)) => f(answer, bound),
+        _ => answer == bound,
+    }
+}
+
+/// Works out the outcome from the article AoC responds with, plus how long to wait
+pub fn parse_response(body: &str) -> (Outcome, Option<u64>) {
+    let outcome = if body.contains("That's the right answer") {
+        Outcome::Correct
+    } else if body.contains("You gave an answer too recently") {
+        Outcome::Wait
+    } else if body.contains("You don't seem to be solving the right level") {
+        Outcome::AlreadySolved
+    } else if body.contains("your answer is too high") {
+        Outcome::TooHigh
+    } else if body.contains("your answer is too low") {
+        Outcome::TooLow
+    } else if body.contains("That's not the right answer") {
+        Outcome::Wrong
+    } else {
+        Outcome::Unknown
+    };
+
+    (outcome, parse_wait(body))
+}
+
+fn parse_wait(body: &str) -> Option<u64> {
+    let left = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    let minutes = Regex::new(r"wait (one|\d+) minutes? before trying again").unwrap();
+
+    if let Some(caps) = left.captures(body) {
+        let m = caps.get(1).map_or(0, |m| m.as_str().parse().unwrap_or(0));
+        let s = caps[2].parse().unwrap_or(0);
+        return Some(m * 60 + s);
+    }
+
+    minutes.captures(body).map(|caps| match &caps[1] {
+        "one" => 60,
+        n => n.parse::<u64>().unwrap_or(1) * 60,
+    })
+}
+
+/// Posts `answer` and records the attempt, unless the history already rules it out
+pub fn submit(
+    base_url: &str,
+    session: &str,
+    history: &mut History,
+    (year, day, part): (u32, u32, u8),
+    answer: &str,
+    now: u64,
+) -> Result<Outcome> {
+    history.check(year, day, part, answer, now)?;
+
+    let url = format!(
+        "{}/{}/day/{}/answer",
+        base_url.trim_end_matches('/'),
+        year,
+        day
+    );
+    let request_error = |message: String| AocError::Request {
+        url: url.clone(),
+        message,
+    };
+    let body = ureq::post(&url)
+        .set("Cookie", &format!("session={}", session))
+        .send_form(&[("level", &part.to_string()), ("answer", answer)])
+        .map_err(|e| request_error(e.to_string()))?
+        .into_string()
+        .map_err(|e| request_error(e.to_string()))?;
+
+    let (outcome, wait) = parse_response(&body);
+    history.record(Submission {
+        year,
+        day,
+        part,
+        answer: answer.to_string(),
+        outcome,
+        at: now,
+        wait_until: wait.map(|w| now + w),
+    });
+
+    Ok(outcome)
+}
+
+/// Runs the part on the day's input and submits whatever it answers
+pub fn submit_day(year: u32, day: u32, part: u8, solution: &dyn Runner) -> Result<()> {
+    let parts = if part == 1 { Parts::One } else { Parts::Two };
+    let run = run_day(year, day, solution, None, parts)?.run;
+
+    let result = if part == 1 { run.part1 } else { run.part2 };
+
+    let answer = match result.transpose()?.map(|p| p.answer) {
+        Some(Answer::Solved(answer)) => answer,
+        _ => {
+            return Err(AocError::Solution(format!(
+                "Part {} has no answer to submit",
+                part
+            )))
+        }
+    };
+
+    let path = PathBuf::from(HISTORY_FILE);
+    let mut history = History::load(&path)?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Clock should be after the epoch")
+        .as_secs();
+
+    println!(
+        "Submitting {} for {} Day {} Part {}",
+        answer, year, day, part
+    );
+    let result = submit(
+        &config().base_url,
+        &session()?,
+        &mut history,
+        (year, day, part),
+        &answer,
+        now,
+    );
+    history.save(&path)?;
+
+    println!("{}", result?);
+    Ok(())
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Outcome::Correct => "right answer",
+            Outcome::Wrong => "wrong answer",
+            Outcome::TooHigh => "wrong answer, too high",
+            Outcome::TooLow => "wrong answer, too low",
+            Outcome::Wait => "answered too recently, wait before trying again",
+            Outcome::AlreadySolved => "already solved",
+            Outcome::Unknown => "unrecognised response",
+        };
+
+        write!(f, "{}", message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::tests::{serve, temp_dir};
+
+    const WRONG: &str = "<article><p>That's not the right answer; your answer is too high. \
+        If you're stuck, make sure you're using the full input data. \
+        Please wait one minute before trying again.</p></article>";
+    const WAIT: &str = "<article><p>You gave an answer too recently; you have to wait after \
+        submitting an answer before trying again.  You have 1m 23s left to wait.</p></article>";
+    const RIGHT: &str = "<article><p>That's the right answer!  You are one gold star closer \
+        to saving the snow operations.</p></article>";
+
+    fn submission(answer: &str, outcome: Outcome, wait_until: Option<u64>) -> Submission {
+        Submission {
+            year: 2023,
+            day: 6,
+            part: 1,
+            answer: answer.to_string(),
+            outcome,
+            at: 0,
+            wait_until,
+        }
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(parse_response(WRONG), (Outcome::TooHigh, Some(60)));
+        assert_eq!(parse_response(WAIT), (Outcome::Wait, Some(83)));
+        assert_eq!(parse_response(RIGHT), (Outcome::Correct, None));
+        assert_eq!(
+            parse_response(
+                "That's not the right answer. Please wait 5 minutes before trying again."
+            ),
+            (Outcome::Wrong, Some(300))
+        );
+        assert_eq!(parse_response("<html></html>"), (Outcome::Unknown, None));
+    }
+
+    #[test]
+    fn test_history_check() {
+        let mut history = History::default();
+        history.record(submission("100", Outcome::TooHigh, Some(60)));
+        history.record(submission("10", Outcome::TooLow, None));
+        history.record(submission("abc", Outcome::Wrong, None));
+
+        assert!(matches!(
+            history.check(2023, 6, 1, "50", 30),
+            Err(AocError::RateLimited { seconds: 30 })
+        ));
+        assert!(history.check(2023, 6, 1, "50", 60).is_ok());
+        assert!(history.check(2023, 6, 2, "100", 0).is_ok());
+
+        for answer in ["100", "150", "10", "5", "abc"] {
+            assert!(matches!(
+                history.check(2023, 6, 1, answer, 60),
+                Err(AocError::AlreadySubmitted { .. })
+            ));
+        }
+
+        history.record(submission("50", Outcome::Correct, None));
+        assert!(history.check(2023, 6, 1, "51", 60).is_err());
+    }
+
+    #[test]
+    fn test_history_load() {
+        let dir = temp_dir("history");
+        let path = dir.join(HISTORY_FILE);
+
+        assert!(History::load(&path).unwrap().0.is_empty());
+
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, "not json").unwrap();
+        assert!(matches!(
+            History::load(&path),
+            Err(AocError::History { .. })
+        ));
+
+        assert!(matches!(History::load(&dir), Err(AocError::History { .. })));
+    }
+
+    #[test]
+    fn test_submit() {
+        let (url, server) = serve("200 OK", WRONG);
+        let mut history = History::default();
+
+        let outcome = submit(&url, "abc", &mut history, (2023, 6, 1), "100", 1000).unwrap();
+        assert_eq!(outcome, Outcome::TooHigh);
+        assert_eq!(
+            history.0,
+            vec![Submission {
+                at: 1000,
+                ..submission("100", Outcome::TooHigh, Some(1060))
+            }]
+        );
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2023/day/6/answer HTTP/1.1"));
+        assert!(request.contains("session=abc"));
+        assert!(request.ends_with("level=1&answer=100"));
+
+        // Resubmitting is refused locally, no server needed
+        let err = submit(&url, "abc", &mut history, (2023, 6, 1), "100", 2000).unwrap_err();
+        assert!(matches!(err, AocError::AlreadySubmitted { .. }));
+        assert_eq!(history.0.len(), 1);
+    }
+}
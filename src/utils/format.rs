@@ -0,0 +1,485 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use super::parse::{ParseError, ParseResult};
+
+/// Declares structs along with a `FromStr` that follows a line pattern. `{field}` takes
+/// everything up to the next literal part of the pattern, `{field:sep}` splits it on `sep`
+/// for collection fields, and `{{`/`}}` are literal braces. Values are trimmed. A pattern
+/// with an unclosed placeholder, or that doesn't match the fields, fails to compile.
+///
+/// ```ignore
+/// line_format! {
+///     #[format = "Game {id}: {rounds:;}"]
+///     struct Game { id: u32, rounds: Vec<Round> }
+///
+///     #[format = "{cubes:,}"]
+///     struct Round { cubes: Vec<String> }
+/// }
+/// ```
+#[macro_export]
+macro_rules! line_format {
+    () => {};
+
+    (
+        #[format = $pattern:literal]
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $( $fvis:vis $field:ident : $ty:ty ),* $(,)?
+        }
+
+        $($rest:tt)*
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $( $fvis $field: $ty, )*
+        }
+
+        impl $crate::utils::format::Field for $name {
+            fn parse_field(
+                line: &str,
+                part: &str,
+                sep: Option<&str>,
+            ) -> $crate::utils::parse::ParseResult<Self> {
+                static PATTERN: $crate::utils::format::Pattern =
+                    $crate::utils::format::Pattern::new($pattern, &[$(stringify!($field)),*]);
+
+                let captures = PATTERN.captures(line, part, sep)?;
+
+                Ok(Self {
+                    $( $field: captures.field(stringify!($field))?, )*
+                })
+            }
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = $crate::utils::parse::ParseError;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                <Self as $crate::utils::format::Field>::parse_field(s, s, None)
+            }
+        }
+
+        $crate::line_format!($($rest)*);
+    };
+}
+
+/// A value that can fill a placeholder. `part` is a slice of `line`, which errors are
+/// reported against, and `sep` is set when the placeholder asked for a split.
+pub trait Field: Sized {
+    fn parse_field(line: &str, part: &str, sep: Option<&str>) -> ParseResult<Self>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Segment<'p> {
+    Literal(&'p str),
+    Field(&'p str, Option<&'p str>),
+}
+
+fn segments(pattern: &str) -> Vec<Segment<'_>> {
+    let mut segments = vec![];
+    let mut rest = pattern;
+
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix("{{") {
+            segments.push(Segment::Literal("{"));
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("}}") {
+            segments.push(Segment::Literal("}"));
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix('{') {
+            let end = after
+                .find('}')
+                .expect("Patterns are checked in Pattern::new");
+            let (name, sep) = match after[..end].split_once(':') {
+                Some((name, sep)) => (name, Some(sep)),
+                None => (&after[..end], None),
+            };
+            segments.push(Segment::Field(name.trim(), sep));
+            rest = &after[end + 1..];
+        } else {
+            let end = rest.find(['{', '}']).unwrap_or(rest.len()).max(1);
+            segments.push(Segment::Literal(&rest[..end]));
+            rest = &rest[end..];
+        }
+    }
+
+    segments
+}
+
+/// The next placeholder at or after `from` as the bounds of its trimmed name, and where
+/// scanning carries on after it
+const fn next_placeholder(pattern: &[u8], mut from: usize) -> Option<(usize, usize, usize)> {
+    while from < pattern.len() {
+        let escaped = from + 1 < pattern.len() && pattern[from + 1] == pattern[from];
+
+        match pattern[from] {
+            b'{' | b'}' if escaped => from += 2,
+            b'{' => {
+                let (mut start, mut end) = (from + 1, from + 1);
+
+                while end < pattern.len() && pattern[end] != b'}' && pattern[end] != b':' {
+                    end += 1;
+                }
+                let mut close = end;
+                while close < pattern.len() && pattern[close] != b'}' {
+                    close += 1;
+                }
+                if close == pattern.len() {
+                    panic!("Unclosed placeholder in a line_format! pattern");
+                }
+
+                while start < end && pattern[start].is_ascii_whitespace() {
+                    start += 1;
+                }
+                while end > start && pattern[end - 1].is_ascii_whitespace() {
+                    end -= 1;
+                }
+
+                return Some((start, end, close + 1));
+            }
+            _ => from += 1,
+        }
+    }
+
+    None
+}
+
+const fn names_match(pattern: &[u8], start: usize, end: usize, name: &str) -> bool {
+    let name = name.as_bytes();
+
+    if end - start != name.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < name.len() {
+        if pattern[start + i] != name[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// A [`line_format!`] pattern, checked against its struct's fields when it's declared and
+/// split into segments the first time it's used
+pub struct Pattern {
+    pattern: &'static str,
+    segments: OnceLock<Vec<Segment<'static>>>,
+}
+
+impl Pattern {
+    pub const fn new(pattern: &'static str, names: &[&str]) -> Self {
+        let bytes = pattern.as_bytes();
+        let mut from = 0;
+
+        while let Some((start, end, next)) = next_placeholder(bytes, from) {
+            let mut i = 0;
+            while i < names.len() && !names_match(bytes, start, end, names[i]) {
+                i += 1;
+            }
+            if i == names.len() {
+                panic!("A line_format! placeholder is not a field");
+            }
+            from = next;
+        }
+
+        let mut i = 0;
+        while i < names.len() {
+            let mut found = false;
+            let mut from = 0;
+
+            while let Some((start, end, next)) = next_placeholder(bytes, from) {
+                found |= names_match(bytes, start, end, names[i]);
+                from = next;
+            }
+            if !found {
+                panic!("A field is missing from its line_format! pattern");
+            }
+            i += 1;
+        }
+
+        Self {
+            pattern,
+            segments: OnceLock::new(),
+        }
+    }
+
+    fn segments(&self) -> &[Segment<'static>] {
+        self.segments.get_or_init(|| segments(self.pattern))
+    }
+
+    /// Matches `part` of `line` against the pattern
+    pub fn captures<'l>(
+        &self,
+        line: &'l str,
+        part: &'l str,
+        sep: Option<&str>,
+    ) -> ParseResult<Captures<'l, 'static>> {
+        if sep.is_some() {
+            return Err(ParseError::at(line, part, "this value can't be split"));
+        }
+
+        let segments = self.segments();
+        let mut fields = vec![];
+        let mut rest = part.trim_end_matches(['\r', '\n']);
+
+        for (i, segment) in segments.iter().enumerate() {
+            match *segment {
+                Segment::Literal(literal) => match rest.strip_prefix(literal) {
+                    Some(after) => rest = after,
+                    None => {
+                        return Err(ParseError::at(
+                            line,
+                            rest,
+                            format!("expected `{}`", literal),
+                        ))
+                    }
+                },
+                Segment::Field(name, sep) => {
+                    let end = match segments.get(i + 1) {
+                        Some(Segment::Literal(next)) => rest.find(next).ok_or_else(|| {
+                            ParseError::at(line, rest, format!("expected `{}`", next))
+                        })?,
+                        _ => rest.len(),
+                    };
+                    fields.push((name, &rest[..end], sep));
+                    rest = &rest[end..];
+                }
+            }
+        }
+
+        if !rest.trim().is_empty() {
+            return Err(ParseError::at(line, rest, "unexpected trailing input"));
+        }
+
+        Ok(Captures { line, fields })
+    }
+}
+
+/// The slice each placeholder matched, used by [`line_format!`]
+pub struct Captures<'l, 'p> {
+    line: &'l str,
+    fields: Vec<(&'p str, &'l str, Option<&'p str>)>,
+}
+
+impl Captures<'_, '_> {
+    pub fn field<T: Field>(&self, name: &str) -> ParseResult<T> {
+        let (_, part, sep) = self
+            .fields
+            .iter()
+            .find(|(field, _, _)| *field == name)
+            .expect("Fields are checked in Pattern::new");
+
+        T::parse_field(self.line, part.trim(), *sep)
+    }
+}
+
+/// Splits on `sep`, or whitespace without one, skipping empty pieces
+fn split<'a>(part: &'a str, sep: Option<&'a str>) -> Box<dyn Iterator<Item = &'a str> + 'a> {
+    let pieces: Box<dyn Iterator<Item = &str>> = match sep {
+        Some(sep) if !sep.trim().is_empty() => Box::new(part.split(sep)),
+        _ => Box::new(part.split_whitespace()),
+    };
+
+    Box::new(pieces.map(str::trim).filter(|p| !p.is_empty()))
+}
+
+impl<T: Field> Field for Vec<T> {
+    fn parse_field(line: &str, part: &str, sep: Option<&str>) -> ParseResult<Self> {
+        split(part, sep)
+            .map(|piece| T::parse_field(line, piece, None))
+            .collect()
+    }
+}
+
+impl<T: Field + Hash + Eq> Field for HashSet<T> {
+    fn parse_field(line: &str, part: &str, sep: Option<&str>) -> ParseResult<Self> {
+        split(part, sep)
+            .map(|piece| T::parse_field(line, piece, None))
+            .collect()
+    }
+}
+
+/// A single value, which can't be split like a collection can
+pub fn value<T: FromStr>(line: &str, part: &str, sep: Option<&str>) -> ParseResult<T> {
+    match sep {
+        Some(_) => Err(ParseError::at(line, part, "this value can't be split")),
+        None => super::parse::value(line, part),
+    }
+}
+
+/// Lets any `FromStr` type fill a placeholder, e.g. a day's own enums
+#[macro_export]
+macro_rules! impl_field {
+    () => {};
+
+    ( $($type:ty $(,)?)* ) => {
+        $(
+            impl $crate::utils::format::Field for $type {
+                fn parse_field(
+                    line: &str,
+                    part: &str,
+                    sep: Option<&str>,
+                ) -> $crate::utils::parse::ParseResult<Self> {
+                    $crate::utils::format::value(line, part, sep)
+                }
+            }
+        )*
+    };
+}
+
+impl_field!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, bool, String);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Color {
+        Red,
+        Green,
+        Blue,
+    }
+
+    impl FromStr for Color {
+        type Err = ();
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s {
+                "red" => Ok(Color::Red),
+                "green" => Ok(Color::Green),
+                "blue" => Ok(Color::Blue),
+                _ => Err(()),
+            }
+        }
+    }
+
+    impl_field!(Color);
+
+    line_format! {
+        #[format = "Game {id}: {rounds:;}"]
+        #[derive(Debug, PartialEq)]
+        struct Game {
+            id: u32,
+            rounds: Vec<Round>,
+        }
+
+        #[format = "{cubes:,}"]
+        #[derive(Debug, PartialEq)]
+        struct Round {
+            cubes: Vec<Cubes>,
+        }
+
+        #[format = "{count} {color}"]
+        #[derive(Debug, PartialEq)]
+        struct Cubes {
+            count: u32,
+            color: Color,
+        }
+
+        #[format = "{name}{{{rules:,}}}"]
+        #[derive(Debug, PartialEq)]
+        struct Workflow {
+            name: String,
+            rules: Vec<String>,
+        }
+    }
+
+    #[test]
+    fn test_segments() {
+        assert_eq!(
+            segments("{name}{{{rules:,}}}"),
+            vec![
+                Segment::Field("name", None),
+                Segment::Literal("{"),
+                Segment::Field("rules", Some(",")),
+                Segment::Literal("}"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pattern() {
+        static PATTERN: Pattern = Pattern::new("{ name }{{{rules:,}}}", &["name", "rules"]);
+
+        let segments = PATTERN.segments();
+        assert_eq!(segments.len(), 4);
+        assert!(std::ptr::eq(segments, PATTERN.segments()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unclosed placeholder")]
+    fn test_pattern_unclosed() {
+        Pattern::new("Game {id}: {rounds", &["id", "rounds"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "placeholder is not a field")]
+    fn test_pattern_unknown() {
+        Pattern::new("Game {id}: {round}", &["id", "rounds"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "missing from its line_format! pattern")]
+    fn test_pattern_missing() {
+        Pattern::new("Game {id}", &["id", "rounds"]);
+    }
+
+    #[test]
+    fn test_nested() {
+        let game: Game = "Game 3: 8 green, 6 blue; 5 red".parse().unwrap();
+
+        assert_eq!(game.id, 3);
+        assert_eq!(
+            game.rounds,
+            vec![
+                Round {
+                    cubes: vec![
+                        Cubes {
+                            count: 8,
+                            color: Color::Green
+                        },
+                        Cubes {
+                            count: 6,
+                            color: Color::Blue
+                        },
+                    ]
+                },
+                Round {
+                    cubes: vec![Cubes {
+                        count: 5,
+                        color: Color::Red
+                    }]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_braces() {
+        let workflow: Workflow = "px{a<2006:qkq,m>2090:A,rfg}".parse().unwrap();
+
+        assert_eq!(workflow.name, "px");
+        assert_eq!(workflow.rules, vec!["a<2006:qkq", "m>2090:A", "rfg"]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = "Game 3: 8 green, 6 purple".parse::<Game>().unwrap_err();
+        assert_eq!(err.column, 20);
+        assert_eq!(err.message, "`purple` is not a valid value");
+
+        let err = "Game three 8 green".parse::<Game>().unwrap_err();
+        assert_eq!(err.column, 6);
+        assert_eq!(err.message, "expected `: `");
+
+        let err = "Card 3: 8 green".parse::<Game>().unwrap_err();
+        assert_eq!(err.column, 1);
+        assert_eq!(err.message, "expected `Game `");
+    }
+}
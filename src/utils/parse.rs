@@ -0,0 +1,261 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::error::AocError;
+
+/// Where parsing failed, `line` and `column` start at 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+pub type ParseResult<T> = std::result::Result<T, ParseError>;
+
+impl ParseError {
+    /// An error at `part`, which must be a slice of `line`
+    pub(crate) fn at(line: &str, part: &str, message: impl Into<String>) -> Self {
+        Self {
+            line: 1,
+            column: column(line, part),
+            message: message.into(),
+        }
+    }
+
+    fn offset(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+}
+
+/// 1-based column of `part` within `line`, counted in chars
+fn column(line: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+    line.get(..offset).map_or(0, |s| s.chars().count()) + 1
+}
+
+pub(crate) fn value<T: FromStr>(line: &str, part: &str) -> ParseResult<T> {
+    part.parse()
+        .map_err(|_| ParseError::at(line, part, format!("`{}` is not a valid value", part)))
+}
+
+/// Every signed integer in `line`, anything else is skipped
+pub fn ints<T: FromStr>(line: &str) -> ParseResult<Vec<T>> {
+    let bytes = line.as_bytes();
+    let mut found = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let negative = bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+        if !bytes[i].is_ascii_digit() && !negative {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        found.push(value(line, &line[start..i])?);
+    }
+
+    Ok(found)
+}
+
+/// Runs `f` over every line, errors are reported against the line they came from
+pub fn lines<'a, T>(
+    input: &'a str,
+    mut f: impl FnMut(&'a str) -> ParseResult<T>,
+) -> ParseResult<Vec<T>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| e.offset(i)))
+        .collect()
+}
+
+/// A run of lines between blank lines, `line` being where it starts in the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    pub line: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Section<'a> {
+    /// [`lines`] with errors reported against the whole input
+    pub fn lines<T>(&self, f: impl FnMut(&'a str) -> ParseResult<T>) -> ParseResult<Vec<T>> {
+        lines(self.text, f).map_err(|e| e.offset(self.line - 1))
+    }
+}
+
+/// Splits `input` on blank lines, dropping the blank lines themselves
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    let mut sections = vec![];
+    let mut start: Option<(usize, usize)> = None;
+    let mut offset = 0;
+
+    for (i, line) in input.split_inclusive('\n').enumerate() {
+        match (line.trim().is_empty(), start) {
+            (true, Some((line, from))) => {
+                sections.push(Section {
+                    line,
+                    text: input[from..offset].trim_end(),
+                });
+                start = None;
+            }
+            (false, None) => start = Some((i + 1, offset)),
+            _ => {}
+        }
+        offset += line.len();
+    }
+
+    if let Some((line, from)) = start {
+        sections.push(Section {
+            line,
+            text: input[from..].trim_end(),
+        });
+    }
+
+    sections
+}
+
+/// Splits `key: values` on the first colon, both sides trimmed
+pub fn key_value(line: &str) -> ParseResult<(&str, &str)> {
+    line.split_once(':')
+        .map(|(key, values)| (key.trim(), values.trim()))
+        .ok_or_else(|| ParseError::at(line, line.trim_end(), "expected `key: values`"))
+}
+
+/// A `key: values` line with the values separated by whitespace or commas
+pub fn record<T: FromStr>(line: &str) -> ParseResult<(&str, Vec<T>)> {
+    let (key, values) = key_value(line)?;
+    let values = values
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|v| !v.is_empty())
+        .map(|v| value(line, v))
+        .collect::<ParseResult<_>>()?;
+
+    Ok((key, values))
+}
+
+/// A graph line like `a -> b, c`, giving the source and its targets
+pub fn edges(line: &str) -> ParseResult<(&str, Vec<&str>)> {
+    let (from, to) = line
+        .split_once("->")
+        .ok_or_else(|| ParseError::at(line, line.trim_end(), "expected `from -> to`"))?;
+
+    let from = from.trim();
+    if from.is_empty() {
+        return Err(ParseError::at(line, line, "missing a source"));
+    }
+
+    let targets = to
+        .split(',')
+        .map(|target| match target.trim() {
+            "" => Err(ParseError::at(line, target, "empty target")),
+            target => Ok(target),
+        })
+        .collect::<ParseResult<_>>()?;
+
+    Ok((from, targets))
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ParseError> for AocError {
+    fn from(value: ParseError) -> Self {
+        AocError::Solution(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(
+            ints::<i32>("p=0,4 v=-3,-3 to-do 12-4").unwrap(),
+            vec![0, 4, -3, -3, 12, -4]
+        );
+        assert!(ints::<u8>("no numbers").unwrap().is_empty());
+
+        let err = ints::<u8>("ok 12 but 300").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 11));
+    }
+
+    #[test]
+    fn test_lines() {
+        let err = lines("1 2\n3 x 4\n5 999", ints::<u8>).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 3: `999` is not a valid value"
+        );
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "a\nb\n\n\nc\n  \nd\ne\n";
+        let sections = sections(input);
+
+        assert_eq!(
+            sections,
+            vec![
+                Section {
+                    line: 1,
+                    text: "a\nb"
+                },
+                Section { line: 5, text: "c" },
+                Section {
+                    line: 7,
+                    text: "d\ne"
+                },
+            ]
+        );
+
+        let err = sections[2].lines(|l| value::<u8>(l, l)).unwrap_err();
+        assert_eq!((err.line, err.column), (7, 1));
+    }
+
+    #[test]
+    fn test_record() {
+        assert_eq!(
+            record::<u32>("Time:      7  15   30").unwrap(),
+            ("Time", vec![7, 15, 30])
+        );
+        assert_eq!(
+            key_value("Game 1: 3 blue; 4 red").unwrap(),
+            ("Game 1", "3 blue; 4 red")
+        );
+
+        let err = record::<u32>("Distance:  9 forty").unwrap_err();
+        assert_eq!(err.column, 14);
+        assert_eq!(key_value("no colon").unwrap_err().column, 1);
+    }
+
+    #[test]
+    fn test_edges() {
+        assert_eq!(edges("%a -> b, con").unwrap(), ("%a", vec!["b", "con"]));
+        assert_eq!(
+            edges("broadcaster -> a").unwrap(),
+            ("broadcaster", vec!["a"])
+        );
+
+        assert_eq!(edges("a => b").unwrap_err().column, 1);
+        assert_eq!(edges("a -> b,, c").unwrap_err().column, 8);
+        assert!(edges(" -> b").is_err());
+    }
+}
@@ -1,67 +1,66 @@
 #![allow(unused)]
 
-use crate::{get_puzzle, time_it};
+use crate::error::Result;
+use crate::solution::{Answer, Solution};
 
-fn parse(input: &str) -> Vec<i32> {
+fn parse(input: &str) -> Result<Vec<i32>> {
     let mut calories = input
     .split("\n\n")
-    .map(|line: &str| line.split("\n").map(|num: &str| num.parse::<i32>().unwrap_or_default()).sum())
-    .collect::<Vec<i32>>();
+    .map(|elf: &str| elf.lines().filter(|l| !l.trim().is_empty()).map(|num: &str| num.trim().parse::<i32>()).sum())
+    .collect::<std::result::Result<Vec<i32>, _>>()?;
 
     calories.sort_by_key(|n| -1 * n);
 
-    calories
+    Ok(calories)
 }
 
-fn solution_pt1(input: &str) -> i32 {
-    let calories = parse(input);
+fn solution_pt1(calories: &[i32]) -> Result<i32> {
+    Ok(*calories.first().ok_or("There are no elves")?)
 
-    *&calories[0]
+}
 
+fn solution_pt2(calories: &[i32]) -> Result<i32> {
+    Ok(calories.get(0..3).ok_or("There are fewer than three elves")?.iter().sum())
 }
 
-fn solution_pt2(input: &str) -> i32 {
-    let calories = parse(input);
+pub struct Day;
 
-    calories[0..3].iter().sum()
-}
+impl Solution for Day {
+    type Input<'i> = Vec<i32>;
 
-pub fn main() {
-    let puzzle = get_puzzle("22", "1");
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
 
-    time_it!("Part 1", solution_pt1(&puzzle));
-    time_it!("Part 2", solution_pt2(&puzzle));
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt2(input)?.into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    const TEST: &'static str = "\
-1000
-2000
-3000
-
-4000
-
-5000
-6000
-
-7000
-8000
-9000
-
-10000
-";
+    const TEST: &str = include_str!("../../examples/y22/day1.txt");
 
     #[test]
     fn test_solution1() {
-        let res = solution_pt1(TEST);
+        let res = solution_pt1(&parse(TEST).unwrap()).unwrap();
         assert_eq!(res, 24000)
     }
 
     #[test]
     fn test_solution2() {
-        let res = solution_pt2(TEST);
+        let res = solution_pt2(&parse(TEST).unwrap()).unwrap();
         assert_eq!(res, 45000)
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_bad_input() {
+        assert!(parse("100\nabc\n").is_err());
+        assert!(solution_pt2(&parse("100\n\n200\n").unwrap()).is_err());
+    }
+}
@@ -1,26 +1,30 @@
+use tracing::debug;
 
-use crate::{get_puzzle, time_it};
+use crate::error::{AocError, Result};
+use crate::solution::{Answer, Solution};
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum RPS {
+pub enum RPS {
     Rock = 1,
     Paper,
     Scissors
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Result {
+enum Outcome {
     Win = 6,
     Lose = 0,
     Draw = 3
 }
 
 impl RPS {
-    fn from_string(input: &str) -> Self {
+    fn from_string(input: &str) -> Result<Self> {
         match input {
-            "A" | "X" => RPS::Rock,
-            "B" | "Y" => RPS::Paper,
-            _ => RPS::Scissors 
+            "A" | "X" => Ok(RPS::Rock),
+            "B" | "Y" => Ok(RPS::Paper),
+            "C" | "Z" => Ok(RPS::Scissors),
+            other => Err(AocError::Solution(format!("Unknown shape {:?}", other)))
         }
     }
 
@@ -33,42 +37,30 @@ impl RPS {
     }
 }
 
-impl Result {
-    fn from_string(input: &str) -> Self {
-        match input {
-            "X" => Result::Lose,
-            "Y" => Result::Draw,
-            _ => Result::Win
+impl Outcome {
+    /// The second column read as the outcome, `X` being a loss and `Y` a draw
+    fn from_rps(rps: RPS) -> Self {
+        match rps {
+            RPS::Rock => Outcome::Lose,
+            RPS::Paper => Outcome::Draw,
+            RPS::Scissors => Outcome::Win
         }
     }
 }
 
-fn parse_pt1(input: &str) -> Vec<(RPS, RPS)> {
-    input
-        .trim()
-        .lines()
-        .map(|l| {
-            let game = l.split(" ")
-                .map(|rps| RPS::from_string(rps))
-                .collect::<Vec<RPS>>();
-            (*&game[0], *&game[1])
-        })
-        .collect()
-}
-
-fn parse_pt2(input: &str) -> Vec<(RPS, Result)> {
+fn parse(input: &str) -> Result<Vec<(RPS, RPS)>> {
     input
         .trim()
         .lines()
         .map(|l| {
-            let game: Vec<&str> = l.split(" ").collect();
-            (RPS::from_string(&game[0]), Result::from_string(&game[1]) )
+            let (them, us) = l.split_once(' ')
+                .ok_or_else(|| AocError::Solution(format!("A round needs two shapes: {:?}", l)))?;
+            Ok((RPS::from_string(them)?, RPS::from_string(us)?))
         })
         .collect()
 }
 
-fn solution_pt1(input: &str) -> u32 {
-    let puzzle: Vec<(RPS, RPS)> = parse_pt1(input);
+fn solution_pt1(puzzle: &[(RPS, RPS)]) -> u32 {
     let mut total: u32 = 0;
 
     for p in puzzle.iter() {
@@ -86,48 +78,58 @@ fn solution_pt1(input: &str) -> u32 {
     total
 }
 
-fn solution_pt2(input: &str) -> u32 {
-    let puzzle = parse_pt2(input);
+fn solution_pt2(puzzle: &[(RPS, RPS)]) -> u32 {
     let mut total: u32 = 0;
 
-    for p in puzzle.iter() {
-        let result: u32 = match p {
-            (rps, Result::Draw) => Result::Draw as u32 + *rps as u32,
+    for &(rps, outcome) in puzzle.iter() {
+        let p = (rps, Outcome::from_rps(outcome));
+        let result: u32 = match &p {
+            (rps, Outcome::Draw) => Outcome::Draw as u32 + *rps as u32,
             (RPS::Rock, r) => (*r as u32) + RPS::Rock.invert() as u32,
             (RPS::Paper, r) => (*r as u32) + RPS::Paper.invert() as u32,
             (RPS::Scissors, r) => (*r as u32) + RPS::Scissors.invert() as u32
         };
-        println!("{:?} {}",p, result);
+        debug!("{:?} {}",p, result);
         total += result;
     }
 
     total
 }
 
-pub fn main() {
-    let puzzle = get_puzzle("22", "2");
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'i> = Vec<(RPS, RPS)>;
 
-    time_it!("Part 1", solution_pt1(&puzzle));
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt1(input).into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    const TEST: &'static str = "\
-A Y
-B X
-C Z
-";
+    const TEST: &str = include_str!("../../examples/y22/day2.txt");
 
     #[test]
     fn test_solution1() {
-        let res = solution_pt1(TEST);
+        let res = solution_pt1(&parse(TEST).unwrap());
         assert_eq!(res, 15);
     }
 
     #[test]
     fn test_solution2() {
-        let res = solution_pt2(TEST);
+        let res = solution_pt2(&parse(TEST).unwrap());
         assert_eq!(res, 12)
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_bad_input() {
+        assert!(parse("A").is_err());
+        assert!(parse("A Q").is_err());
+    }
+}
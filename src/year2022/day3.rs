@@ -1,10 +1,21 @@
 use std::collections::HashSet;
 
+use crate::error::Result;
+use crate::solution::Solution;
+
 fn parse(input: &str) {
     let rucksacks: Vec<(HashSet<&str>, HashSet<&str>)> = vec![];
 
     for line in input.trim().split("\n") {}
 }
 
-pub fn main() {}
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'i> = &'i str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+}
 
@@ -373,7 +373,7 @@ fn solution_pt2(input: &str) -> u64 {
 }
 
 pub fn main() {
-    let puzzle = get_puzzle("23", "5");
+    let puzzle = get_puzzle(2023, 5);
 
     time_it!("Part 1", solution_pt1(&puzzle));
     time_it!("Part 2", solution_pt2(&puzzle));
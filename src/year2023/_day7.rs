@@ -268,7 +268,7 @@ fn solution_pt2(input: &str) -> u32 {
 }
 
 pub fn main() {
-    let puzzle = get_puzzle("23", "7");
+    let puzzle = get_puzzle(2023, 7);
 
     time_it!("Part 1", solution_pt1(&puzzle));
     time_it!("Part 2", solution_pt2(&puzzle));
@@ -1,13 +1,13 @@
 use std::collections::HashMap;
-use std::fs::read_to_string;
-use std::path::PathBuf;
-use std::time::Instant;
+
+use crate::error::Result;
+use crate::solution::{Answer, Solution};
 
 fn parse(input: &str) -> Vec<&str> {
     input.lines().collect()
 }
 
-fn solution_pt1(data: &Vec<&str>) -> u32 {
+fn solution_pt1(data: &Vec<&str>) -> Result<u32> {
     let mut result: u32 = 0;
 
     for line in data {
@@ -21,14 +21,14 @@ fn solution_pt1(data: &Vec<&str>) -> u32 {
 
         if !number.is_empty() {
             let first_last = format!("{}{}", number[0], number[number.len() - 1]);
-            result += first_last.parse::<u32>().unwrap();
+            result += first_last.parse::<u32>()?;
         }
     }
 
-    result
+    Ok(result)
 }
 
-fn solution_pt2(data: &Vec<&str>) -> u32 {
+fn solution_pt2(data: &Vec<&str>) -> Result<u32> {
     let word_to_num: HashMap<&str, &str> = HashMap::from([
         ("one", "1"),
         ("two", "2"),
@@ -47,49 +47,55 @@ fn solution_pt2(data: &Vec<&str>) -> u32 {
         let mut cur_line = line.to_owned();
 
         let first = 'outer: loop {
-            let l: Vec<char> = cur_line.chars().collect();
-            if l[0].is_numeric() {
-                break l[0].to_string();
+            let first = cur_line.chars().next().ok_or("A line has no digits")?;
+            if first.is_numeric() {
+                break first.to_string();
             }
 
             for key in word_to_num.keys() {
                 if cur_line.starts_with(key) {
-                    break 'outer word_to_num.get(key).unwrap().to_string();
+                    break 'outer word_to_num[key].to_string();
                 }
             }
-            cur_line = &cur_line[1..];
+            cur_line = &cur_line[first.len_utf8()..];
         };
 
         let last = 'outer: loop {
-            let l: Vec<char> = cur_line.chars().collect();
-            if l[l.len() - 1].is_numeric() {
-                break l[l.len() - 1].to_string();
+            let last = cur_line.chars().last().ok_or("A line has no digits")?;
+            if last.is_numeric() {
+                break last.to_string();
             }
 
             for key in word_to_num.keys() {
                 if cur_line.ends_with(key) {
-                    break 'outer word_to_num.get(key).unwrap().to_string();
+                    break 'outer word_to_num[key].to_string();
                 }
             }
 
-            cur_line = &cur_line[..cur_line.len() - 1];
+            cur_line = &cur_line[..cur_line.len() - last.len_utf8()];
         };
 
-        result += format!("{}{}", first, last).parse::<u32>().unwrap();
+        result += format!("{}{}", first, last).parse::<u32>()?;
     }
 
-    result
+    Ok(result)
 }
 
-pub fn main() {
-    let path: PathBuf = ["input", "y23", "day1.txt"].iter().collect();
-    let data = read_to_string(path).expect("Not there");
-    let data_parsed = parse(&data);
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'i> = Vec<&'i str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse(input))
+    }
 
-    let start = Instant::now();
-    let res = solution_pt1(&data_parsed);
-    let res2: u32 = solution_pt2(&data_parsed);
-    let duration = start.elapsed().as_micros();
-    println!("{res} {res2} {duration}")
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt2(input)?.into())
+    }
 }
 
@@ -3,18 +3,20 @@ use std::{
     ops::Deref,
 };
 
-use crate::{get_puzzle, time_it};
+use crate::error::Result;
+use crate::solution::{Answer, Solution};
 
-use ::grid::prelude::{Cell, IntoCell};
+use ::grid::prelude::{Cell, CellLike, IntoCell};
 use grid::grid::Sided;
 use num_integer::Roots;
+use tracing::debug;
 
 type Grid = ::grid::prelude::Grid<Pipe, Looped>;
 type Coords = (usize, usize);
 type Queue<'q> = VecDeque<(Coords, u32)>;
 
 #[derive(Debug, Default, Clone, PartialEq)]
-enum Pipe {
+pub enum Pipe {
     Start,
     NorthWest,
     NorthEast,
@@ -36,7 +38,7 @@ enum Direction {
 }
 
 #[derive(Debug, Default, Clone)]
-enum Looped {
+pub enum Looped {
     In,
     #[default]
     Out,
@@ -195,15 +197,21 @@ fn try_escape(grid: &mut Grid, cell: Coords) -> bool {
     false
 }
 
-fn solution_pt1(input: &str) -> u32 {
-    let mut grid = Grid::new_four_sided(input);
+fn parse(input: &str) -> Result<Grid> {
+    if !input.contains('S') {
+        return Err("The map has no starting pipe".into());
+    }
+
+    Ok(Grid::new_four_sided(input))
+}
 
-    bfs(&mut grid)
+fn solution_pt1(grid: &Grid) -> u32 {
+    bfs(&mut grid.clone())
 }
 
-fn solution_pt2(input: &str) -> u32 {
+fn solution_pt2(grid: &Grid) -> u32 {
     use Looped::*;
-    let mut grid = Grid::new_four_sided(input);
+    let mut grid = grid.clone();
 
     let _ = bfs(&mut grid);
     let mut count = 0;
@@ -213,7 +221,7 @@ fn solution_pt2(input: &str) -> u32 {
         let extras = grid[(y, x)].extras.clone();
 
         if let Out = extras {
-            println!("{} {}", y, x);
+            debug!("{} {}", y, x);
             if !try_escape(&mut grid, (y, x)) {
                 grid[(y, x)].extras = In;
                 count += 1;
@@ -221,75 +229,39 @@ fn solution_pt2(input: &str) -> u32 {
             }
         }
     }
-    println!("{:?}", escape_coords);
+    debug!("{:?}", escape_coords);
 
     count
 }
 
-pub fn main() {
-    let puzzle = get_puzzle("23", "10");
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'i> = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
 
-    time_it!("Solution Pt 1", solution_pt1(&puzzle));
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt1(input).into())
+    }
 }
 
 #[cfg(test)]
-const TEST_ONE: &str = "\
-.....
-.S-7.
-.|.|.
-.L-J.
-.....
-";
+const TEST_ONE: &str = include_str!("../../examples/y23/day10.txt");
 
 #[cfg(test)]
-const TEST_TWO: &str = "\
-..F7.
-.FJ|.
-SJ.L7
-|F--J
-LJ...
-";
+const TEST_TWO: &str = include_str!("../../examples/y23/day10-2.txt");
 
 #[cfg(test)]
-const TEST_THREE: &str = "\
-FF7FSF7F7F7F7F7F---7
-L|LJ||||||||||||F--J
-FL-7LJLJ||||||LJL-77
-F--JF--7||LJLJ7F7FJ-
-L---JF-JLJ.||-FJLJJ7
-|F|F-JF---7F7-L7L|7|
-|FFJF7L7F-JF7|JL---7
-7-L-JL7||F7|L7F-7F7|
-L.L7LFJ|||||FJL7||LJ
-L7JLJL-JLJLJL--JLJ.L
-";
+const TEST_THREE: &str = include_str!("../../examples/y23/day10-3.txt");
 
 #[cfg(test)]
-const TEST_FOUR: &str = "\
-...........
-.S-------7.
-.|F-----7|.
-.||.....||.
-.||.....||.
-.|L-7.F-J|.
-.|..|.|..|.
-.L--J.L--J.
-...........
-";
+const TEST_FOUR: &str = include_str!("../../examples/y23/day10-4.txt");
 
 #[cfg(test)]
-const TEST_FIVE: &str = "\
-.F----7F7F7F7F-7....
-.|F--7||||||||FJ....
-.||.FJ||||||||L7....
-FJL7L7LJLJ||LJ.L-7..
-L--J.L7...LJS7F-7L7.
-....F-J..F7FJ|L7L7L7
-....L7.F7||L7|.L7L7|
-.....|FJLJ|FJ|F7|.LJ
-....FJL-7.||.||||...
-....L---J.LJ.LJLJ...
-";
+const TEST_FIVE: &str = include_str!("../../examples/y23/day10-5.txt");
 
 #[cfg(test)]
 mod tests {
@@ -297,25 +269,31 @@ mod tests {
 
     #[test]
     fn test_solution_pt1_one() {
-        let res = solution_pt1(TEST_ONE);
+        let res = solution_pt1(&Grid::new_four_sided(TEST_ONE));
 
         assert_eq!(res, 4);
     }
 
     #[test]
     fn test_solutions_pt1_two() {
-        let res = solution_pt1(TEST_TWO);
+        let res = solution_pt1(&Grid::new_four_sided(TEST_TWO));
 
         assert_eq!(res, 8);
     }
 
     #[test]
     fn test_solution_pt2() {
-        let res = solution_pt2(TEST_FIVE);
+        let res = solution_pt2(&Grid::new_four_sided(TEST_FIVE));
 
         assert_eq!(res, 8);
     }
 
+    #[test]
+    fn test_bad_input() {
+        assert!(parse("-7\n|J\n").is_err());
+        assert!(parse(TEST_ONE).is_ok());
+    }
+
     #[test]
     fn test_directions() {
         assert_eq!(Direction::from_target((3, 2), (3, 3)), Direction::East);
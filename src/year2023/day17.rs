@@ -1,11 +1,11 @@
 use std::cmp::Reverse;
 use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
 use std::ops::Deref;
-use std::usize;
 
 use ::grid::prelude::{Coords, Cursor, Dijkstra, Direction, Directional, IntoCell};
 
-use crate::{get_puzzle, time_it};
+use crate::error::{AocError, Result};
+use crate::solution::{Answer, Solution};
 
 type Grid = ::grid::prelude::Grid<usize, ()>;
 type Cell = ::grid::prelude::Cell<usize, ()>;
@@ -133,73 +133,82 @@ fn _bfs(grid: &Grid, last: Coords) -> usize {
     best_heat
 }
 
-fn _solution_pt1(input: &str) -> usize {
-    let grid = Grid::new_four_sided(input);
-    let last = ((grid.rows as i32) - 1, (grid.cols as i32) - 1);
-
-    let end = |node: &Facing| **node == last;
-    let cost = |node: &Facing| grid.get_cell_signed(**node).map(|c| c.val);
-    let neighbors = |node: Facing| node.neighbors();
-
-    let bfs = Dijkstra::new(&neighbors, &cost, &end);
+/// Moves that stay on the grid, costing the heat lost in the block moved into
+fn heat(grid: &Grid, next: Vec<Facing>) -> Vec<(Facing, usize)> {
+    next.into_iter()
+        .filter_map(|node| grid.get_cell_signed(*node).map(|c| (node, c.val)))
+        .collect()
+}
 
-    let res = bfs.cost(vec![
+fn start() -> [Facing; 2] {
+    [
         Facing::new((0, 0), Direction::East),
         Facing::new((0, 0), Direction::South),
-    ]);
+    ]
+}
+
+fn _solution_pt1(grid: &Grid) -> usize {
+    let last = ((grid.rows as i32) - 1, (grid.cols as i32) - 1);
+
+    let res = Dijkstra::new()
+        .neighbors(|node: &Facing| heat(grid, node.neighbors()))
+        .goal(|node: &Facing| **node == last)
+        .cost(start());
 
     res.unwrap_or(0)
 }
 
-fn _solution_pt2(input: &str) -> usize {
-    let grid = Grid::new_four_sided(input);
+fn _solution_pt2(grid: &Grid) -> usize {
     let last = ((grid.rows as i32) - 1, (grid.cols as i32) - 1);
 
-    let end = |node: &Facing| **node == last && node.count >= 4;
-    let cost = |node: &Facing| grid.get_cell_signed(**node).map(|c| c.val);
-    let neighbors = |node: Facing| {
-        if node.count < 4 {
+    let neighbors = |node: &Facing| {
+        let next = if node.count < 4 {
             vec![node.forward()]
         } else if node.count >= 4 && node.count < 10 {
             vec![node.forward(), node.left(), node.right()]
         } else {
             vec![node.left(), node.right()]
-        }
+        };
+
+        heat(grid, next)
     };
 
-    let bfs = Dijkstra::new(&neighbors, &cost, &end);
-
-    let res = bfs.cost(vec![
-        Facing::new((0, 0), Direction::East),
-        Facing::new((0, 0), Direction::South),
-    ]);
+    let res = Dijkstra::new()
+        .neighbors(neighbors)
+        .goal(|node: &Facing| **node == last && node.count >= 4)
+        .cost(start());
 
     res.unwrap_or(0)
 }
 
-pub fn main() {
-    let puzzle = get_puzzle("23", "17");
+fn parse(input: &str) -> Result<Grid> {
+    if let Some(c) = input.chars().find(|c| !c.is_ascii_digit() && !matches!(c, '\n' | '\r')) {
+        return Err(AocError::Solution(format!("Unknown heat loss {c:?}")));
+    }
+
+    Ok(Grid::new_four_sided(input))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'i> = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(_solution_pt1(input).into())
+    }
 
-    time_it!("Solution Pt 1", _solution_pt1(&puzzle));
-    time_it!("Solution Pt 2", _solution_pt2(&puzzle));
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(_solution_pt2(input).into())
+    }
 }
 
 // #[cfg(test)]
-const TEST_ONE: &str = "\
-2413432311323
-3215453535623
-3255245654254
-3446585845452
-4546657867536
-1438598798454
-4457876987766
-3637877979653
-4654967986887
-4564679986453
-1224686865563
-2546548887735
-4322674655533
-";
+const TEST_ONE: &str = include_str!("../../examples/y23/day17.txt");
 
 #[cfg(test)]
 mod tests {
@@ -207,15 +216,28 @@ mod tests {
 
     #[test]
     fn test_one() {
-        let res = _solution_pt1(TEST_ONE);
+        let res = _solution_pt1(&Grid::new_four_sided(TEST_ONE));
 
         assert_eq!(res, 102);
     }
 
     #[test]
     fn test_one_1() {
-        let res = _solution_pt1(TEST_ONE);
+        let res = _solution_pt1(&Grid::new_four_sided(TEST_ONE));
 
         assert_eq!(res, 102);
     }
+
+    #[test]
+    fn test_two() {
+        let res = _solution_pt2(&Grid::new_four_sided(TEST_ONE));
+
+        assert_eq!(res, 94);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(parse("123\n4x6\n").is_err());
+        assert!(parse(TEST_ONE).is_ok());
+    }
 }
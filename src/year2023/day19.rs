@@ -2,7 +2,8 @@ use std::collections::HashMap;
 
 use regex::Regex;
 
-use crate::{get_puzzle, time_it};
+use crate::error::{AocError, Result};
+use crate::solution::{Answer, Solution};
 
 macro_rules! parse_op {
     () => {};
@@ -39,7 +40,7 @@ struct Job<'w> {
     end: &'w str,
 }
 
-struct Machine<'w> {
+pub struct Machine<'w> {
     flow: Workflow<'w>,
     parts: Vec<Parts>,
 }
@@ -53,7 +54,7 @@ impl Machine<'_> {
             let mut score = 0;
 
             loop {
-                let job = self.flow.get(current).unwrap();
+                let job = &self.flow[current];
 
                 match job.test(part) {
                     "A" => {
@@ -90,13 +91,14 @@ impl Parts {
     }
 }
 
-fn parse<'w>(input: &'w str) -> Machine<'w> {
+fn parse<'w>(input: &'w str) -> Result<Machine<'w>> {
     let work_regex = Regex::new(r"(\w+)\{(.+),(\w+)\}\n").unwrap();
     let inner_regex = Regex::new(r"(\w+)(<|>)(\d+):(\w+),?").unwrap();
     let parts_regex = Regex::new(r"\{x=(\d+),m=(\d+),a=(\d+),s=(\d+)\}\n").unwrap();
 
     let mut flow = Workflow::new();
     let mut parts = vec![];
+    let mut targets = vec!["in"];
 
     for (_, [outer, inner_op, last]) in work_regex.captures_iter(input).map(|c| c.extract()) {
         // println!("{} {} {}", outer, last, inner_op);
@@ -105,14 +107,17 @@ fn parse<'w>(input: &'w str) -> Machine<'w> {
         for (_, [first, op, digit, target]) in
             inner_regex.captures_iter(inner_op).map(|c| c.extract())
         {
-            jobs_vec.push(Box::new(parse_op!(
-                first,
-                op,
-                digit.parse::<u32>().unwrap(),
-                target
-            )));
+            if !matches!(first, "x" | "m" | "a" | "s") {
+                return Err(AocError::Solution(format!("Unknown category {first}")));
+            }
+
+            let digit = digit.parse::<u32>()?;
+            jobs_vec.push(Box::new(parse_op!(first, op, digit, target)));
+            targets.push(target);
         }
 
+        targets.push(last);
+
         let job = Job {
             jobs: jobs_vec,
             end: last,
@@ -123,51 +128,45 @@ fn parse<'w>(input: &'w str) -> Machine<'w> {
 
     for (_, [x, m, a, s]) in parts_regex.captures_iter(input).map(|c| c.extract()) {
         let (x, m, a, s) = (
-            x.parse().unwrap(),
-            m.parse().unwrap(),
-            a.parse().unwrap(),
-            s.parse().unwrap(),
+            x.parse()?,
+            m.parse()?,
+            a.parse()?,
+            s.parse()?,
         );
 
         parts.push(Parts(x, m, a, s));
     }
 
-    Machine { flow, parts }
-}
+    if let Some(target) = targets
+        .into_iter()
+        .find(|t| !matches!(*t, "A" | "R") && !flow.contains_key(t))
+    {
+        return Err(AocError::Solution(format!("Unknown workflow {target}")));
+    }
 
-fn solution_pt1(input: &str) -> u32 {
-    let machine = parse(input);
+    Ok(Machine { flow, parts })
+}
 
+fn solution_pt1(machine: &Machine) -> u32 {
     machine.run()
 }
 
-pub fn main() {
-    let puzzle = get_puzzle("23", "19");
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'i> = Machine<'i>;
 
-    time_it!("Solution Pt 1", solution_pt1(&puzzle));
-    // time_it!("solution Pt 2", solution_pt2(&puzzle));
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt1(input).into())
+    }
 }
 
 #[cfg(test)]
-const TEST_ONE: &str = "\
-px{a<2006:qkq,m>2090:A,rfg}
-pv{a>1716:R,A}
-lnx{m>1548:A,A}
-rfg{s<537:gd,x>2440:R,A}
-qs{s>3448:A,lnx}
-qkq{x<1416:A,crn}
-crn{x>2662:A,R}
-in{s<1351:px,qqz}
-qqz{s>2770:qs,m<1801:hdj,R}
-gd{a>3333:R,R}
-hdj{m>838:A,pv}
-
-{x=787,m=2655,a=1222,s=2876}
-{x=1679,m=44,a=2067,s=496}
-{x=2036,m=264,a=79,s=2244}
-{x=2461,m=1339,a=466,s=291}
-{x=2127,m=1623,a=2188,s=1013}
-";
+const TEST_ONE: &str = include_str!("../../examples/y23/day19.txt");
 
 #[cfg(test)]
 mod tests {
@@ -175,7 +174,15 @@ mod tests {
 
     #[test]
     fn test_parse() {
-        parse(TEST_ONE);
+        parse(TEST_ONE).unwrap();
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(parse("in{x<10:qq,A}\n\n{x=1,m=2,a=3,s=4}\n").is_err());
+        assert!(parse("in{q<10:R,A}\n\n{x=1,m=2,a=3,s=4}\n").is_err());
+        assert!(parse("in{x<99999999999:R,A}\n\n{x=1,m=2,a=3,s=4}\n").is_err());
+        assert!(parse("px{x<10:R,A}\n\n{x=1,m=2,a=3,s=4}\n").is_err());
     }
 
     #[test]
@@ -188,7 +195,7 @@ mod tests {
 
     #[test]
     fn test_one() {
-        let res = solution_pt1(TEST_ONE);
+        let res = solution_pt1(&parse(TEST_ONE).unwrap());
 
         assert_eq!(res, 19114)
     }
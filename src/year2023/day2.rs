@@ -3,7 +3,8 @@
 use regex::Regex;
 use std::cmp::max;
 
-use crate::{get_puzzle, time_it};
+use crate::error::Result;
+use crate::solution::{Answer, Solution};
 
 #[derive(Debug)]
 enum Colors {
@@ -15,12 +16,12 @@ enum Colors {
 #[derive(Debug)]
 struct SubGame(Colors, u32, u32);
 
-struct Game {
+pub struct Game {
     no: u32,
     games: Vec<SubGame>,
 }
 
-fn parse(input: &str) -> Vec<Game> {
+fn parse(input: &str) -> Result<Vec<Game>> {
     let game = Regex::new(r"Game (\d+): (.+\n)").unwrap();
     let ginfo = Regex::new(r"\s*(\d+) (blue|green|red)(,|;|\n)").unwrap();
     // input.lines().collect()
@@ -31,10 +32,10 @@ fn parse(input: &str) -> Vec<Game> {
 
         for (_, [num, col, sep]) in ginfo.captures_iter(g).map(|c| c.extract()) {
             let sg = match col {
-                "blue" => SubGame(Colors::Blue, num.parse::<u32>().unwrap(), subg_no),
-                "red" => SubGame(Colors::Red, num.parse::<u32>().unwrap(), subg_no),
-                "green" => SubGame(Colors::Green, num.parse::<u32>().unwrap(), subg_no),
-                _ => panic!(),
+                "blue" => SubGame(Colors::Blue, num.parse::<u32>()?, subg_no),
+                "red" => SubGame(Colors::Red, num.parse::<u32>()?, subg_no),
+                "green" => SubGame(Colors::Green, num.parse::<u32>()?, subg_no),
+                _ => unreachable!("The pattern only matches the three colors"),
             };
 
             if sep != "," {
@@ -43,14 +44,18 @@ fn parse(input: &str) -> Vec<Game> {
 
             games.push(sg)
         }
+        if games.is_empty() {
+            return Err("A game has no cubes".into());
+        }
+
         let new_game = Game {
-            no: g_no.parse::<u32>().unwrap(),
+            no: g_no.parse::<u32>()?,
             games,
         };
         res.push(new_game);
     }
 
-    res
+    Ok(res)
 }
 
 const MAX_RED: u32 = 12;
@@ -73,8 +78,8 @@ impl Tracker {
         }
     }
 
-    fn add(&mut self, sg: SubGame) {
-        match (sg.0, sg.1) {
+    fn add(&mut self, sg: &SubGame) {
+        match (&sg.0, sg.1) {
             (Colors::Red, s) => self.red = max(self.red, s),
             (Colors::Blue, s) => self.blue = max(self.blue, s),
             (Colors::Green, s) => self.green = max(self.green, s),
@@ -101,8 +106,7 @@ impl Tracker {
     }
 }
 
-fn solution_pt1(input: &str) -> u32 {
-    let games = parse(input);
+fn solution_pt1(games: &[Game]) -> u32 {
     let mut tracker = Tracker::new();
 
     let mut sum: u32 = 0;
@@ -110,7 +114,7 @@ fn solution_pt1(input: &str) -> u32 {
         let mut sg_no = game.games[0].2;
         let mut valid: bool = true;
 
-        for sub in game.games {
+        for sub in &game.games {
             if sg_no != sub.2 {
                 sg_no = sub.2;
                 if !tracker.check_valid() {
@@ -131,13 +135,12 @@ fn solution_pt1(input: &str) -> u32 {
     sum
 }
 
-fn solution_pt2(input: &str) -> u32 {
-    let games = parse(input);
+fn solution_pt2(games: &[Game]) -> u32 {
     let mut tracker = Tracker::new();
     let mut sum_power: u32 = 0;
 
     for game in games {
-        for sub in game.games {
+        for sub in &game.games {
             tracker.add(sub);
         }
         sum_power += tracker.power();
@@ -147,36 +150,46 @@ fn solution_pt2(input: &str) -> u32 {
     sum_power
 }
 
-pub fn main() {
-    let data = get_puzzle("23", "2");
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'i> = Vec<Game>;
 
-    time_it!("Part 1", solution_pt1(&data));
-    time_it!("Part 2", solution_pt2(&data));
-    // solution_pt2(&data);
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt2(input).into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const TEST: &str = "\
-Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
-Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
-Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
-Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
-Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
-";
+    const TEST: &str = include_str!("../../examples/y23/day2.txt");
 
     #[test]
     fn test_pt1() {
-        let res = solution_pt1(TEST);
+        let res = solution_pt1(&parse(TEST).unwrap());
         assert_eq!(res, 8)
     }
 
     #[test]
     fn test_pt2() {
-        let res = solution_pt2(TEST);
+        let res = solution_pt2(&parse(TEST).unwrap());
         assert_eq!(res, 2286)
     }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(parse("Game 1: 3 blue\nGame 99999999999: 1 red\n").is_err());
+        assert!(parse("Game 1: lots of cubes\n").is_err());
+    }
 }
 
@@ -1,9 +1,10 @@
 use regex::Regex;
 use std::collections::{HashMap, HashSet, VecDeque};
 
-use crate::{debug_output_logger, get_puzzle, time_it};
-use log::info;
+use crate::error::{AocError, Result};
+use crate::solution::{Answer, Solution};
 use num_integer::Integer;
+use tracing::trace;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum Pulse {
@@ -33,7 +34,7 @@ struct Conjunction<'b> {
 }
 
 #[derive(Debug, Clone)]
-struct Broadcaster<'b> {
+pub struct Broadcaster<'b> {
     modules: HashMap<&'b str, Modules<'b>>,
     report: Vec<&'b str>,
 }
@@ -51,12 +52,10 @@ impl<'b> Broadcaster<'b> {
 
         if let Some(Broadcast(b)) = self.modules.get("broadcaster") {
             queue.extend(b.iter().map(|m| Instructions("broadcaster", m, Pulse::Low)));
-        } else {
-            panic!("Should not happen")
         }
 
         while let Some(Instructions(from, to, pulse)) = queue.pop_front() {
-            // info!("From - {}, To - {}, Pulse - {:?}", from, to, pulse);
+            trace!("From - {}, To - {}, Pulse - {:?}", from, to, pulse);
 
             if let ("xn", Pulse::High) = (to, pulse) {
                 self.report.push(from);
@@ -128,7 +127,7 @@ impl<'b> Conjunction<'b> {
     }
 }
 
-fn parse(input: &str) -> Broadcaster<'_> {
+fn parse(input: &str) -> Result<Broadcaster<'_>> {
     let regex = Regex::new(r"(.+) -> (.+)\n").unwrap();
     let mut modules = HashMap::new();
     let mut modules_to_check = HashSet::new();
@@ -156,25 +155,29 @@ fn parse(input: &str) -> Broadcaster<'_> {
                     }),
                 );
             }
-            _ => {}
+            other => return Err(AocError::Solution(format!("Unknown module {other}"))),
         };
     }
 
+    if !matches!(modules.get("broadcaster"), Some(Modules::Broadcast(_))) {
+        return Err("There is no broadcaster".into());
+    }
+
     for (from, to) in modules_to_check {
         if let Some(Modules::Junction(conj)) = modules.get_mut(to) {
             conj.input.insert(from, Pulse::Low);
         }
     }
 
-    Broadcaster {
+    Ok(Broadcaster {
         modules,
         report: vec![],
-    }
+    })
 }
 
-fn solution_pt1(input: &str, tries: usize) -> usize {
+fn solution_pt1(broadcaster: &Broadcaster, tries: usize) -> usize {
     let (mut high, mut low) = (0, 0);
-    let mut broadcaster = parse(input);
+    let mut broadcaster = broadcaster.clone();
 
     for i in 0..tries {
         let (h, l) = broadcaster.start();
@@ -185,12 +188,12 @@ fn solution_pt1(input: &str, tries: usize) -> usize {
     high * low
 }
 
-fn solution_pt2(input: &str) -> usize {
-    let mut broadcaster = parse(input);
+fn solution_pt2(broadcaster: &Broadcaster) -> Result<usize> {
+    let mut broadcaster = broadcaster.clone();
     let mut targets = if let Some(Modules::Junction(conj)) = broadcaster.modules.get("xn") {
         conj.input.clone()
     } else {
-        panic!("Should exist")
+        return Err("There is no xn conjunction".into());
     };
     let mut count = 0;
     let mut cycles = vec![];
@@ -205,33 +208,32 @@ fn solution_pt2(input: &str) -> usize {
         }
     }
 
-    cycles.iter().fold(1, |acc, num| acc.lcm(num))
+    Ok(cycles.iter().fold(1, |acc, num| acc.lcm(num)))
 }
 
-pub fn main() {
-    let puzzle = get_puzzle("23", "20");
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'i> = Broadcaster<'i>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
 
-    time_it!("Solution Pt 1", solution_pt1(&puzzle, 1000));
-    time_it!("Solution Pt 2", solution_pt2(&puzzle));
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt1(input, 1000).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt2(input)?.into())
+    }
 }
 
 #[cfg(test)]
-const TEST_ONE: &str = "\
-broadcaster -> a, b, c
-%a -> b
-%b -> c
-%c -> inv
-&inv -> a
-";
+const TEST_ONE: &str = include_str!("../../examples/y23/day20.txt");
 
 // #[cfg(test)]
-const TEST_TWO: &str = "\
-broadcaster -> a
-%a -> inv, con
-&inv -> b
-%b -> con
-&con -> output
-";
+const TEST_TWO: &str = include_str!("../../examples/y23/day20-2.txt");
 
 #[cfg(test)]
 mod tests {
@@ -239,22 +241,29 @@ mod tests {
 
     #[test]
     fn test_solution_1() {
-        let res = solution_pt1(TEST_ONE, 1);
+        let res = solution_pt1(&parse(TEST_ONE).unwrap(), 1);
 
         assert_eq!(res, 32);
     }
 
     #[test]
     fn test_solution_1_1000() {
-        let res = solution_pt1(TEST_ONE, 1000);
+        let res = solution_pt1(&parse(TEST_ONE).unwrap(), 1000);
 
         assert_eq!(res, 32000000);
     }
 
     #[test]
     fn test_solution_1_2() {
-        let res = solution_pt1(TEST_TWO, 1000);
+        let res = solution_pt1(&parse(TEST_TWO).unwrap(), 1000);
 
         assert_eq!(res, 11687500);
     }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(parse("%a -> b\n").is_err());
+        assert!(parse("broadcaster -> a\n?a -> b\n").is_err());
+        assert!(solution_pt2(&parse(TEST_ONE).unwrap()).is_err());
+    }
 }
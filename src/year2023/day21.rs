@@ -1,15 +1,14 @@
-use std::collections::{HashMap, HashSet, VecDeque};
-use std::hash::Hash;
+use std::collections::{HashSet, VecDeque};
 
-use grid::prelude::{Cursor, Direction, IntoCell, QueueItem};
+use grid::prelude::{bfs, CellLike, Coords, IntoCell, SparseCoords};
 
-use crate::{get_puzzle, time_it};
+use crate::error::{AocError, Result};
+use crate::solution::{Answer, Solution};
 
 type Grid = ::grid::prelude::Grid<Path, ()>;
-type Cell = ::grid::prelude::Cell<Path, ()>;
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
-enum Path {
+pub enum Path {
     #[default]
     Plot,
     Rock,
@@ -32,47 +31,109 @@ impl IntoCell<'_, Path> for Path {
     }
 }
 
-fn solution_pt1(input: &str, max: usize) -> usize {
-    let grid = Grid::new_four_sided(input);
-    let start = grid.find(Path::Start).unwrap();
-    let mut visited: HashSet<&Cell> = HashSet::new();
-    visited.insert(start);
-
-    for _ in 0..max {
-        let mut reached = HashSet::new();
-        for v in visited {
-            for neighbor in grid.neighbors(v) {
-                if matches!(neighbor.val, Path::Plot | Path::Start) {
-                    reached.insert(neighbor);
-                }
+fn parse(input: &str) -> Result<Grid> {
+    if let Some(c) = input.chars().find(|c| !matches!(c, '.' | '#' | 'S' | '\n' | '\r')) {
+        return Err(AocError::Solution(format!("Unknown tile {c:?}")));
+    }
+    if input.matches('S').count() != 1 {
+        return Err("The map must have exactly one start".into());
+    }
+
+    Ok(Grid::new_four_sided(input))
+}
+
+fn solution_pt1(grid: &Grid, max: usize) -> usize {
+    let start = grid
+        .find(Path::Start)
+        .expect("The start is checked in parse")
+        .coords();
+    let neighbors = |&coords: &Coords| {
+        grid.neighbors(&grid[coords])
+            .into_iter()
+            .filter(|n| n.val != Path::Rock)
+            .map(|n| n.coords())
+            .collect::<Vec<_>>()
+    };
+
+    // A plot can always be revisited by stepping away and back
+    bfs([start], neighbors, |_| false)
+        .distances
+        .values()
+        .filter(|&&d| d <= max && d % 2 == max % 2)
+        .count()
+}
+
+/// Plots reachable in exactly `steps` on the infinitely repeated map
+fn reachable_tiled(grid: &Grid, steps: usize) -> usize {
+    let tiled = grid.tiled();
+    let (y, x) = grid
+        .find(Path::Start)
+        .expect("The start is checked in parse")
+        .signed_coords();
+    let start: SparseCoords = (y as i64, x as i64);
+
+    let mut seen: HashSet<SparseCoords> = HashSet::from([start]);
+    let mut queue: VecDeque<(SparseCoords, usize)> = VecDeque::from([(start, 0)]);
+    let mut count = 0;
+
+    while let Some((coords, dist)) = queue.pop_front() {
+        // A plot can always be revisited by stepping away and back
+        if dist % 2 == steps % 2 {
+            count += 1;
+        }
+        if dist == steps {
+            continue;
+        }
+
+        for (neighbor, cell) in tiled.neighbors(coords) {
+            if cell.val != Path::Rock && seen.insert(neighbor) {
+                queue.push_back((neighbor, dist + 1));
             }
         }
-        visited = reached;
     }
 
-    visited.len()
+    count
+}
+
+/// The reachable count grows quadratically every time another map's width is walked, so
+/// three samples are enough to extrapolate it. That needs a square map.
+fn solution_pt2(grid: &Grid, steps: usize) -> Result<usize> {
+    let size = grid.rows;
+    let (n, rem) = (steps / size, steps % size);
+
+    if n < 3 {
+        return Ok(reachable_tiled(grid, steps));
+    }
+    if grid.rows != grid.cols {
+        return Err("The map must be square to extrapolate".into());
+    }
+
+    let [a, b, c] = [0, 1, 2].map(|i| reachable_tiled(grid, rem + i * size) as i64);
+    let n = n as i64;
+
+    Ok((a + n * (b - a) + n * (n - 1) / 2 * (c - 2 * b + a)) as usize)
 }
 
-pub fn main() {
-    let puzzle = get_puzzle("23", "21");
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'i> = Grid;
 
-    time_it!("Solution Pt 1", solution_pt1(&puzzle, 64));
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt1(input, 64).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt2(input, 26501365)?.into())
+    }
 }
 
 #[cfg(test)]
-const TEST_ONE: &str = "\
-...........
-.....###.#.
-.###.##..#.
-..#.#...#..
-....#.#....
-.##..S####.
-.##..#...#.
-.......##..
-.##.#.####.
-.##..##.##.
-...........
-";
+const TEST_ONE: &str = include_str!("../../examples/y23/day21.txt");
 
 #[cfg(test)]
 mod tests {
@@ -80,8 +141,56 @@ mod tests {
 
     #[test]
     fn test_one() {
-        let res = solution_pt1(TEST_ONE, 6);
+        let res = solution_pt1(&Grid::new_four_sided(TEST_ONE), 6);
 
         assert_eq!(res, 16);
     }
+
+    #[test]
+    fn test_two() {
+        let grid = Grid::new_four_sided(TEST_ONE);
+
+        assert_eq!(reachable_tiled(&grid, 6), 16);
+        assert_eq!(reachable_tiled(&grid, 10), 50);
+        assert_eq!(reachable_tiled(&grid, 50), 1594);
+        assert_eq!(solution_pt2(&grid, 10).unwrap(), 50);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        // Like the real input, the start's row and column and the border are clear
+        let input = "\
+.......
+.#.....
+..#.#..
+...S...
+.##....
+.....#.
+.......
+";
+        let grid = Grid::new_four_sided(input);
+
+        for steps in [3 * 7 + 3, 4 * 7 + 3, 5 * 7 + 3, 8 * 7 + 3] {
+            assert_eq!(
+                solution_pt2(&grid, steps).unwrap(),
+                reachable_tiled(&grid, steps)
+            );
+        }
+
+        let input = "\
+...
+.S.
+...
+...
+";
+        assert!(solution_pt2(&Grid::new_four_sided(input), 3 * 4).is_err());
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(parse("...\n.S.\n.x.\n").is_err());
+        assert!(parse("...\n...\n").is_err());
+        assert!(parse(".S.\n.S.\n").is_err());
+        assert!(parse(TEST_ONE).is_ok());
+    }
 }
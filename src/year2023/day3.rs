@@ -1,7 +1,8 @@
 #![allow(unused)]
 use std::{collections::HashSet, ops::Deref};
 
-use crate::{get_puzzle, time_it};
+use crate::error::Result;
+use crate::solution::{Answer, Solution};
 use ::grid::prelude::*;
 
 fn check_adjacent(
@@ -35,7 +36,11 @@ fn check_adjacent(
         }
     }
 
-    number.iter().collect::<String>().parse::<i32>().unwrap()
+    number
+        .iter()
+        .collect::<String>()
+        .parse::<i32>()
+        .expect("Numbers are checked in parse")
 }
 
 fn find_numbers(
@@ -65,6 +70,16 @@ fn find_numbers(
         _ => None,
     }
 }
+/// Every number is read back out of the grid later, so they're checked here
+fn parse(input: &str) -> Result<DefaultGrid<char>> {
+    for number in input.split(|c: char| !c.is_numeric()) {
+        if !number.is_empty() {
+            number.parse::<i32>()?;
+        }
+    }
+
+    Ok(Grid::new(input, Sided::Eight))
+}
 //
 fn solution_pt1(grid: &DefaultGrid<char>) -> i32 {
     let mut nums: Vec<i32> = vec![];
@@ -98,29 +113,28 @@ fn solution_pt2(grid: &DefaultGrid<char>) -> i32 {
     nums.into_iter().sum()
 }
 
-pub fn main() {
-    let puzzle = get_puzzle("23", "3");
-    let grid: Grid<char, ()> = Grid::new(&puzzle, Sided::Eight);
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'i> = DefaultGrid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
 
-    time_it!("Part 1", solution_pt1(&grid));
-    time_it!("Part 2", solution_pt2(&grid));
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt2(input).into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    const TEST: &str = "\
-467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598..
-";
+    const TEST: &str = include_str!("../../examples/y23/day3.txt");
 
     #[test]
     fn solution1() {
@@ -135,4 +149,10 @@ mod tests {
         let res = solution_pt2(&grid);
         assert_eq!(res, 467835)
     }
+
+    #[test]
+    fn test_bad_number() {
+        assert!(parse("467..114..\n...*......\n").is_ok());
+        assert!(parse("99999999999*..\n").is_err());
+    }
 }
@@ -1,42 +1,23 @@
 #![allow(unused)]
-use regex::Regex;
 use std::collections::{BinaryHeap, HashMap, HashSet};
 
-use crate::{get_puzzle, time_it};
+use crate::error::Result;
+use crate::line_format;
+use crate::solution::{Answer, Solution};
+use crate::utils::parse::lines;
 
-fn parse(input: &str) -> Vec<CardGame> {
-    let card_game = Regex::new(r"Card\s*(\d+):\s*(.+) \|\s*(.+)\n").unwrap();
-    let split_cards = Regex::new(r"\s+").unwrap();
-
-    let mut res: Vec<CardGame> = vec![];
-
-    for (_, [g_num, win, has]) in card_game.captures_iter(input).map(|c| c.extract()) {
-        let win_cards: HashSet<u32> = split_cards
-            .split(win)
-            .map(|w| w.trim().parse::<u32>().unwrap())
-            .collect();
-        let have_cards: HashSet<u32> = split_cards
-            .split(has)
-            .map(|w| w.trim().parse::<u32>().unwrap())
-            .collect();
-
-        let new_game = CardGame {
-            no: g_num.parse::<u32>().unwrap(),
-            win: win_cards,
-            has: have_cards,
-        };
-
-        res.push(new_game)
-    }
-
-    res
+fn parse(input: &str) -> Result<Vec<CardGame>> {
+    Ok(lines(input, str::parse)?)
 }
 
-#[derive(Debug)]
-struct CardGame {
-    no: u32,
-    win: HashSet<u32>,
-    has: HashSet<u32>,
+line_format! {
+    #[format = "Card {no}: {win: } | {has: }"]
+    #[derive(Debug)]
+    pub struct CardGame {
+        no: u32,
+        win: HashSet<u32>,
+        has: HashSet<u32>,
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -108,13 +89,13 @@ impl Iterator for GameResult {
     }
 }
 
-fn solution_pt1(input: &str) -> u32 {
-    let games = parse(input);
-    games.iter().map(|cg: &CardGame| cg.get_points_pt1()).sum()
+fn solution_pt1(games: &[CardGame]) -> u32 {
+    games
+        .iter().map(|cg: &CardGame| cg.get_points_pt1()).sum()
 }
 
-fn solution_pt2(input: &str) -> u32 {
-    let mut results: Vec<GameResult> = parse(input)
+fn solution_pt2(games: &[CardGame]) -> u32 {
+    let mut results: Vec<GameResult> = games
         .iter()
         .map(|c: &CardGame| c.get_points_pt2())
         .collect();
@@ -137,39 +118,46 @@ fn solution_pt2(input: &str) -> u32 {
     total
 }
 
-pub fn main() {
-    let puzzle = get_puzzle("23", "4");
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'i> = Vec<CardGame>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt1(input).into())
+    }
 
-    time_it!("Part 1", solution_pt1(&puzzle));
-    time_it!("Part 2", solution_pt2(&puzzle));
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt2(input).into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    const TEST: &str = "\
-Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
-Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
-Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
-Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
-Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
-Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
-";
+    const TEST: &str = include_str!("../../examples/y23/day4.txt");
 
     #[test]
     fn test_parse() {
-        parse(TEST);
+        assert_eq!(parse(TEST).unwrap().len(), 6);
+
+        let err = parse("Card 1: 41 48 | 83 86\nCard 2: 13 x2 | 61").unwrap_err();
+        assert_eq!(err.to_string(), "Solution failed: line 2, column 12: `x2` is not a valid value");
     }
 
     #[test]
     fn test_solution1() {
-        let res = solution_pt1(TEST);
+        let res = solution_pt1(&parse(TEST).unwrap());
         assert_eq!(res, 13)
     }
 
     #[test]
     fn test_solution2() {
-        let res = solution_pt2(TEST);
+        let res = solution_pt2(&parse(TEST).unwrap());
         assert_eq!(res, 30)
     }
 }
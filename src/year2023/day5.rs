@@ -5,11 +5,13 @@ use std::{
     collections::HashMap,
     ops::{Deref, Range},
 };
+use tracing::debug;
 
-use crate::{get_puzzle, time_it};
+use crate::error::Result;
+use crate::solution::{Answer, Solution};
 
-#[derive(Debug)]
-struct Map {
+#[derive(Debug, Clone)]
+pub struct Map {
     rlist: Vec<(Range<usize>, usize)>,
     min: usize,
     max: usize,
@@ -30,18 +32,20 @@ impl Map {
         val
     }
 
-    fn from_string(input: &str) -> Self {
+    fn from_string(input: &str) -> Result<Self> {
         let mut rlist = vec![];
         let mut min = usize::MAX - 1;
         let mut max = 0;
 
         for line in input.lines() {
-            let nums: Vec<usize> = line
+            let nums = line
                 .split(' ')
-                .map(|num| num.trim().parse::<usize>().unwrap())
-                .collect();
+                .map(|num| num.trim().parse::<usize>())
+                .collect::<std::result::Result<Vec<_>, _>>()?;
 
-            let (start, index, base) = (nums[0], nums[1], nums[2]);
+            let [start, index, base] = nums[..] else {
+                return Err("A map line needs three numbers".into());
+            };
             let map_range = (index..index + base, start);
             min = min.min(index);
             max = max.max(index + base);
@@ -49,7 +53,7 @@ impl Map {
             rlist.push(map_range);
         }
 
-        Self { rlist, min, max }
+        Ok(Self { rlist, min, max })
     }
 
     fn intersect(self, other: Map) -> Map {
@@ -73,8 +77,8 @@ impl Map {
             .map(|(range, dest)| (dest..dest + (range.end - range.start), 0))
             .collect::<Vec<_>>();
         other.extend(new_ranges);
-        other.sort_by(|a, b| a.0.start.cmp(&b.0.start));
-        println!("{:?}", other);
+        other.sort_by_key(|a| a.0.start);
+        debug!("{:?}", other);
 
         let mut current = other[0].0.clone();
         let mut rlist: Vec<(Range<usize>, usize)> = vec![];
@@ -144,17 +148,17 @@ fn check_almanac_cached(
     check_almanac_cached(maps, level + 1, val)
 }
 
-fn parse(input: &str) -> (Vec<u64>, Vec<Map>) {
+fn parse(input: &str) -> Result<(Vec<u64>, Vec<Map>)> {
     let mut lines = input.split("\n\n");
-    let seeds = lines.next().unwrap();
+    let seeds = lines.next().ok_or("Missing seeds")?;
 
     let seeds_re = Regex::new(r"seeds: (.+)").unwrap();
     let maps = Regex::new(r".+ map:\n([\s\S]+)").unwrap();
 
-    let seeds: Vec<u64> = seeds_re.captures(seeds).unwrap()[1]
+    let seeds = seeds_re.captures(seeds).ok_or("Missing seeds")?[1]
         .split(' ')
-        .map(|num| num.parse::<u64>().unwrap())
-        .collect();
+        .map(|num| num.parse::<u64>())
+        .collect::<std::result::Result<Vec<_>, _>>()?;
 
     // let mut maps_vec: Vec<Map> = vec![];
     let mut almanac: Vec<Map> = vec![];
@@ -162,27 +166,30 @@ fn parse(input: &str) -> (Vec<u64>, Vec<Map>) {
     for line in lines {
         if let Some(cap) = maps.captures(line) {
             // maps_vec.push(Map::from_string(&cap[1]));
-            almanac.push(Map::from_string(&cap[1]));
+            almanac.push(Map::from_string(&cap[1])?);
         }
     }
 
-    (seeds, almanac)
+    if almanac.len() != 7 {
+        return Err("The almanac needs seven maps".into());
+    }
+
+    Ok((seeds, almanac))
 }
 
-fn solution_pt1(input: &str) -> usize {
-    let (seeds, maps) = parse(input);
+fn solution_pt1((seeds, maps): &(Vec<u64>, Vec<Map>)) -> usize {
     let mut _cache: HashMap<(usize, usize), usize> = HashMap::new();
     let mut min = usize::MAX - 1;
 
-    for seed in seeds {
+    for &seed in seeds {
         // let res = check_almanac(&maps, &mut cache, 0, seed as usize);
-        let res = check_almanac_cached(&maps, 0, seed as usize);
+        let res = check_almanac_cached(maps, 0, seed as usize);
         // println!("seed - {} res - {}", seed, res);
         min = min.min(res);
     }
 
-    println!(
-        "{}",
+    debug!(
+        "cache hits - {}",
         CHECK_ALMANAC_CACHED
             .lock()
             .unwrap()
@@ -193,8 +200,10 @@ fn solution_pt1(input: &str) -> usize {
     min
 }
 
-fn solution_pt2(input: &str) -> usize {
-    let (seeds, maps) = parse(input);
+fn solution_pt2((seeds, maps): &(Vec<u64>, Vec<Map>)) -> Result<usize> {
+    if seeds.len() % 2 != 0 {
+        return Err("Seeds should come in start and length pairs".into());
+    }
     let mut min = usize::MAX - 1;
 
     // for se in seeds.chunks(2) {
@@ -226,57 +235,34 @@ fn solution_pt2(input: &str) -> usize {
     };
 
     for map in maps {
-        current_map = current_map.intersect(map);
+        current_map = current_map.intersect(map.clone());
     }
 
-    println!("{:?}", current_map);
+    debug!("{:?}", current_map);
 
-    min
+    Ok(min)
 }
 
-pub fn main() {
-    let input = get_puzzle("23", "5");
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'i> = (Vec<u64>, Vec<Map>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
 
-    time_it!("Solution Pt 1", solution_pt1(&input));
-    time_it!("Solution Pt 2", solution_pt2(&input));
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt2(input)?.into())
+    }
 }
 
 #[cfg(test)]
-const TEST_MAP: &str = "\
-seeds: 79 14 55 13
-
-seed-to-soil map:
-50 98 2
-52 50 48
-
-soil-to-fertilizer map:
-0 15 37
-37 52 2
-39 0 15
-
-fertilizer-to-water map:
-49 53 8
-0 11 42
-42 0 7
-57 7 4
-
-water-to-light map:
-88 18 7
-18 25 70
-
-light-to-temperature map:
-45 77 23
-81 45 19
-68 64 13
-
-temperature-to-humidity map:
-0 69 1
-1 0 69
-
-humidity-to-location map:
-60 56 37
-56 93 4
-";
+const TEST_MAP: &str = include_str!("../../examples/y23/day5.txt");
 
 #[cfg(test)]
 mod tests {
@@ -284,14 +270,14 @@ mod tests {
 
     #[test]
     fn test_parse() {
-        let parsed = parse(TEST_MAP);
+        let parsed = parse(TEST_MAP).unwrap();
 
         println!("{:?}\n\n{:?}", parsed.0, parsed.1);
     }
 
     #[test]
     fn test_maps() {
-        let (_, maps) = parse(TEST_MAP);
+        let (_, maps) = parse(TEST_MAP).unwrap();
         assert_eq!(maps.len(), 7);
 
         let start_expect = [(79, 82), (14, 43)];
@@ -310,18 +296,24 @@ mod tests {
 
     #[test]
     fn test_pt1() {
-        let res = solution_pt1(TEST_MAP);
+        let res = solution_pt1(&parse(TEST_MAP).unwrap());
 
         assert_eq!(res, 35);
     }
 
     #[test]
     fn test_pt2() {
-        let res = solution_pt2(TEST_MAP);
+        let res = solution_pt2(&parse(TEST_MAP).unwrap()).unwrap();
 
         assert_eq!(res, 46);
     }
 
+    #[test]
+    fn test_bad_input() {
+        assert!(parse("seeds: 79 14\n\nseed-to-soil map:\n50 98\n").is_err());
+        assert!(parse(&TEST_MAP.replace("seeds: 79", "seeds: x")).is_err());
+    }
+
     #[test]
     fn test_intersect() {
         let map1 = Map {
@@ -1,50 +1,25 @@
 #![allow(unused)]
-use regex::Regex;
 use std::ops::Range;
 
-use crate::{get_puzzle, time_it};
+use crate::error::Result;
+use crate::solution::{Answer, Solution};
+use crate::utils::parse::record;
 
-fn parse(input: &str) -> (Vec<u32>, Vec<u32>) {
-    let re = Regex::new(r"(\d+)+").unwrap();
+fn parse(input: &str) -> Result<(Vec<u32>, Vec<u32>)> {
     let mut lines = input.lines();
 
-    let time: Vec<u32> = re
-        .find_iter(lines.next().unwrap())
-        .map(|c| c.as_str().parse::<u32>().unwrap())
-        .collect();
-    let distance: Vec<u32> = re
-        .find_iter(lines.next().unwrap())
-        .map(|c| c.as_str().parse::<u32>().unwrap())
-        .collect();
+    let (_, time) = record(lines.next().ok_or("Missing time line")?)?;
+    let (_, distance) = record(lines.next().ok_or("Missing distance line")?)?;
 
-    (time, distance)
+    Ok((time, distance))
 }
 
-fn parse_pt2(input: &str) -> (u64, u64) {
-    let re = Regex::new(r"(\d+)+").unwrap();
-    let mut lines = input.lines();
-
-    let time: u64 = re
-        .find_iter(lines.next().unwrap())
-        .map(|c| c.as_str())
-        .collect::<Vec<&str>>()
-        .join("")
-        .parse()
-        .unwrap();
-    let distance: u64 = re
-        .find_iter(lines.next().unwrap())
-        .map(|c| c.as_str())
-        .collect::<Vec<&str>>()
-        .join("")
-        .parse()
-        .unwrap();
-
-    (time, distance)
+/// Reads the numbers as one, ignoring the spaces between them
+fn kerned(nums: &[u32]) -> Result<u64> {
+    Ok(nums.iter().map(|n| n.to_string()).collect::<String>().parse()?)
 }
 
-fn solution_pt1(input: &str) -> u32 {
-    let (time, distance): (Vec<u32>, Vec<u32>) = parse(input);
-
+fn solution_pt1((time, distance): &(Vec<u32>, Vec<u32>)) -> Result<u32> {
     let res: u32 = time
         .iter()
         .zip(distance.iter())
@@ -57,7 +32,7 @@ fn solution_pt1(input: &str) -> u32 {
         })
         .product();
 
-    res
+    Ok(res)
 }
 
 fn get_bounds(time: &u64, distance: &u64, reversed: bool) -> u64 {
@@ -77,40 +52,52 @@ fn get_bounds(time: &u64, distance: &u64, reversed: bool) -> u64 {
     }
 }
 
-fn solution_pt2(input: &str) -> u64 {
-    let (time, distance): (u64, u64) = parse_pt2(input);
+fn solution_pt2((time, distance): &(Vec<u32>, Vec<u32>)) -> Result<u64> {
+    let (time, distance) = (kerned(time)?, kerned(distance)?);
 
     let upper = get_bounds(&time, &distance, true);
     let lower = get_bounds(&time, &distance, false);
 
-    time - (upper + lower)
+    Ok(time - (upper + lower))
 }
 
-pub fn main() {
-    let puzzle = get_puzzle("23", "6");
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'i> = (Vec<u32>, Vec<u32>);
 
-    time_it!("Part 1", solution_pt1(&puzzle));
-    time_it!("Part 2", solution_pt2(&puzzle));
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt2(input)?.into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    const TEST: &str = "\
-Time:      7  15   30
-Distance:  9  40  200
-";
+    const TEST: &str = include_str!("../../examples/y23/day6.txt");
 
     #[test]
     fn test_solution1() {
-        let res = solution_pt1(TEST);
+        let res = solution_pt1(&parse(TEST).unwrap()).unwrap();
         assert_eq!(res, 288)
     }
 
     #[test]
     fn test_solution2() {
-        let res = solution_pt2(TEST);
+        let res = solution_pt2(&parse(TEST).unwrap()).unwrap();
         assert_eq!(res, 71503)
     }
-}
 
+    #[test]
+    fn test_missing_line() {
+        assert!(parse("Time:      7  15   30").is_err());
+    }
+}
@@ -1,4 +1,5 @@
-use crate::{debug_output, get_puzzle, time_it};
+use crate::error::{AocError, Result};
+use crate::solution::{Answer, Solution};
 use std::cmp::Reverse;
 use std::sync::{Mutex, OnceLock};
 use std::{collections::HashMap, ops::Deref};
@@ -29,14 +30,34 @@ enum HandType {
 struct Hand(Vec<Card>);
 
 #[derive(Debug)]
-struct Player {
+pub struct Player {
     hand: Hand,
     bid: u32,
 }
 
 impl Hand {
-    fn new(input: &str, part2: bool) -> Self {
-        Self(input.chars().map(|c| Card::new(c, part2)).collect())
+    fn new(input: &str, part2: bool) -> Result<Self> {
+        let cards = input
+            .chars()
+            .map(|c| Card::new(c, part2))
+            .collect::<Result<Vec<_>>>()?;
+
+        match cards.len() {
+            5 => Ok(Self(cards)),
+            _ => Err(AocError::Solution(format!("`{}` is not a hand of five", input))),
+        }
+    }
+
+    /// The same hand with every jack played as a joker
+    fn jokers(&self) -> Self {
+        Self(
+            self.iter()
+                .map(|c| match c {
+                    Card::Jack => Card::Joker,
+                    c => c.clone(),
+                })
+                .collect(),
+        )
     }
 }
 
@@ -79,18 +100,19 @@ impl Deref for Hand {
 }
 
 impl Card {
-    fn new(card: char, part2: bool) -> Self {
+    fn new(card: char, part2: bool) -> Result<Self> {
         use Card::*;
 
-        match card {
+        Ok(match card {
             'A' => Ace,
             'K' => King,
             'Q' => Queen,
             'J' if part2 => Joker,
             'J' => Jack,
             'T' => Ten,
-            a => Num(a.to_string().parse().unwrap()),
-        }
+            '2'..='9' => Num(card.to_digit(10).unwrap_or_default()),
+            a => return Err(AocError::Solution(format!("`{}` is not a card", a))),
+        })
     }
 }
 
@@ -173,23 +195,25 @@ fn counter(hand: &Hand) -> HandType {
     // hand_type
 }
 
-fn parse(input: &str, part2: bool) -> Vec<Player> {
+fn parse(input: &str) -> Result<Vec<Player>> {
     input
         .lines()
         .map(|line| {
-            let mut player = line.split(' ');
-            let mut hand = Hand::new(player.next().unwrap(), part2);
-            let bid = player.next().unwrap().trim().parse().unwrap();
-            Player { hand, bid }
+            let (hand, bid) = line.split_once(' ').ok_or("Expected a hand and a bid")?;
+            let hand = Hand::new(hand, false)?;
+            let bid = bid.trim().parse()?;
+            Ok(Player { hand, bid })
         })
         .collect()
 }
 
-fn solution(input: &str, part2: bool) -> u32 {
-    let players = parse(input, part2);
+fn solution(players: &[Player], part2: bool) -> u32 {
     let mut hands = players
-        .into_iter()
-        .map(|p| (p.bid, counter(&p.hand), p.hand.clone()))
+        .iter()
+        .map(|p| {
+            let hand = if part2 { p.hand.jokers() } else { p.hand.clone() };
+            (p.bid, counter(&hand), hand)
+        })
         .collect::<Vec<_>>();
     hands.sort_by_key(|a| Reverse((a.1.clone(), a.2.clone())));
 
@@ -200,21 +224,26 @@ fn solution(input: &str, part2: bool) -> u32 {
         .fold(0, |acc, (i, bid)| acc + (bid * (i as u32 + 1)))
 }
 
-pub fn main() {
-    let puzzle = get_puzzle("23", "7");
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'i> = Vec<Player>;
 
-    time_it!("Part 1", solution(&puzzle, false));
-    time_it!("Part 2", solution(&puzzle, true));
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution(input, false).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution(input, true).into())
+    }
 }
 
 #[cfg(test)]
-const TEST_ONE: &str = "\
-32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483
-";
+const TEST_ONE: &str = include_str!("../../examples/y23/day7.txt");
 
 #[cfg(test)]
 mod test {
@@ -222,15 +251,22 @@ mod test {
 
     #[test]
     fn test_one() {
-        let res = solution(TEST_ONE, false);
+        let res = solution(&parse(TEST_ONE).unwrap(), false);
 
         assert_eq!(res, 6440)
     }
 
     #[test]
     fn test_two() {
-        let res = solution(TEST_ONE, true);
+        let res = solution(&parse(TEST_ONE).unwrap(), true);
 
         assert_eq!(res, 5905)
     }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(parse("32T3K 765\nT55J5").is_err());
+        assert!(parse("32T3X 765").is_err());
+        assert!(parse("32T3 765").is_err());
+    }
 }
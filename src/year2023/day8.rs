@@ -3,13 +3,14 @@ use std::{collections::HashMap, hash::Hash, iter::Cycle, ops::Deref, str::Chars}
 use num_integer::Integer;
 use regex::Regex;
 
-use crate::{get_puzzle, time_it};
+use crate::error::{AocError, Result};
+use crate::solution::{Answer, Solution};
 
 #[derive(PartialEq, Eq, Hash, Clone, Default, Debug)]
 struct Part2<'lr>(&'lr str, &'lr str);
 
-#[derive(Debug)]
-struct LeftRight<T> {
+#[derive(Debug, Clone)]
+pub struct LeftRight<T> {
     lr: Vec<char>,
     map: HashMap<T, (T, T)>,
     index: usize,
@@ -68,7 +69,7 @@ impl<'lr> Iterator for LeftRight<&'lr str> {
     }
 }
 
-fn parse<'lr, T>(input: &'lr str) -> LeftRight<T>
+fn parse<'lr, T>(input: &'lr str) -> Result<LeftRight<T>>
 where
     T: From<&'lr str> + PartialEq + Eq + Hash + Default,
 {
@@ -77,111 +78,112 @@ where
         Regex::new(r"(?<start>[A-Z0-9]{3}) = \((?<left>[A-Z0-9]{3}), (?<right>[A-Z0-9]{3})\)")
             .unwrap();
 
-    let left_right = left_right_re.captures(input).unwrap()[0].chars().collect();
+    let left_right = left_right_re
+        .captures(input)
+        .ok_or("Missing the left/right instructions")?[0]
+        .chars()
+        .collect();
 
     let mut locations: HashMap<T, (T, T)> = HashMap::new();
 
     for cap in locations_re.captures_iter(input) {
-        let loc = cap.name("start").unwrap().as_str();
-        let left = cap.name("left").unwrap().as_str();
-        let right = cap.name("right").unwrap().as_str();
+        let (_, [loc, left, right]) = cap.extract();
 
         locations.insert(loc.into(), (left.into(), right.into()));
     }
 
-    LeftRight {
+    // Walking the map looks up every node it reaches
+    if locations
+        .values()
+        .any(|(left, right)| !locations.contains_key(left) || !locations.contains_key(right))
+    {
+        return Err("A node leads somewhere that isn't on the map".into());
+    }
+
+    Ok(LeftRight {
         lr: left_right,
         map: locations,
         index: 0,
         prev: T::default(),
         part2: vec![],
-    }
+    })
 }
 
-fn solution_pt1(input: &str) -> u32 {
-    let mut left_right = parse(input);
-    left_right.prev = "AAA";
-    let mut count = 0;
+/// Past this many steps every (node, instruction) pair has repeated, so a walk that hasn't
+/// reached its target by then is stuck in a cycle
+fn steps_limit<T>(left_right: &LeftRight<T>) -> usize {
+    left_right.lr.len() * left_right.map.len()
+}
 
-    for direct in left_right {
-        count += 1;
+fn solution_pt1(left_right: &LeftRight<&str>) -> Result<u32> {
+    if !left_right.map.contains_key("AAA") {
+        return Err("There's no AAA to start from".into());
+    }
+    let mut left_right = left_right.clone();
+    left_right.prev = "AAA";
 
+    for (count, direct) in (1..=steps_limit(&left_right)).zip(left_right) {
         if direct == "ZZZ" {
-            break;
+            return Ok(count as u32);
         }
     }
 
-    count
+    Err("The map never reaches ZZZ".into())
 }
 
-fn solution_pt2(input: &str) -> u64 {
-    let mut left_right = parse(input);
-    left_right.add_part2();
-
+fn solution_pt2(left_right: &LeftRight<&str>) -> Result<u64> {
+    let limit = steps_limit(left_right);
     let mut counts = vec![];
 
-    for key in left_right.part2.iter() {
-        let mut current = key.clone();
+    for &key in left_right.map.keys().filter(|&&k| Part2::from(k).is_a()) {
+        let mut current = key;
         let mut count = 0;
         let mut next_direct = left_right.lr.iter().cycle();
 
-        while !current.is_z() {
-            let item = left_right.map.get(&current).unwrap();
+        while !Part2::from(current).is_z() {
+            if count >= limit {
+                return Err(AocError::Solution(format!("{} never reaches a ..Z node", key)));
+            }
+            let item = left_right.map.get(current).unwrap();
 
             current = match next_direct.next() {
-                Some('L') => item.0.clone(),
-                _ => item.1.clone(),
+                Some('L') => item.0,
+                _ => item.1,
             };
             count += 1;
         }
         counts.push(count);
     }
 
-    counts.iter().fold(1, |acc, x| acc.lcm(x))
+    Ok(counts.iter().fold(1, |acc, &x| acc.lcm(&(x as u64))))
 }
 
-pub fn main() {
-    let puzzle = get_puzzle("23", "8");
+pub struct Day;
 
-    time_it!("Solution Pt 1", solution_pt1(&puzzle));
-    time_it!("Solution Pt 2", solution_pt2(&puzzle));
+impl Solution for Day {
+    type Input<'i> = LeftRight<&'i str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solution_pt2(input)?.into())
+    }
 }
 
 #[cfg(test)]
-const TEST_STR: &str = "\
-RL
-
-AAA = (BBB, CCC)
-BBB = (DDD, EEE)
-CCC = (ZZZ, GGG)
-DDD = (DDD, DDD)
-EEE = (EEE, EEE)
-GGG = (GGG, GGG)
-ZZZ = (ZZZ, ZZZ)
-";
+const TEST_STR: &str = include_str!("../../examples/y23/day8.txt");
 
 #[cfg(test)]
-const TEST_STR2: &str = "\
-LLR
-
-AAA = (BBB, BBB)
-BBB = (AAA, ZZZ)
-ZZZ = (ZZZ, ZZZ)
-";
+const TEST_STR2: &str = include_str!("../../examples/y23/day8-2.txt");
 
 #[cfg(test)]
-const TEST_STR3: &str = "\
-LR
-
-11A = (11B, XXX)
-11B = (XXX, 11Z)
-11Z = (11B, XXX)
-22A = (22B, XXX)
-22B = (22C, 22C)
-22C = (22Z, 22Z)
-22Z = (22B, 22B)
-XXX = (XXX, XXX)
-";
+const TEST_STR3: &str = include_str!("../../examples/y23/day8-3.txt");
 
 #[cfg(test)]
 mod tests {
@@ -189,8 +191,8 @@ mod tests {
 
     #[test]
     fn test_pt1() {
-        let solution = solution_pt1(TEST_STR);
-        let solution2 = solution_pt1(TEST_STR2);
+        let solution = solution_pt1(&parse(TEST_STR).unwrap()).unwrap();
+        let solution2 = solution_pt1(&parse(TEST_STR2).unwrap()).unwrap();
 
         assert_eq!(solution, 2);
         assert_eq!(solution2, 6);
@@ -198,8 +200,21 @@ mod tests {
 
     #[test]
     fn test_pt2() {
-        let solution = solution_pt2(TEST_STR3);
+        let solution = solution_pt2(&parse(TEST_STR3).unwrap()).unwrap();
 
         assert_eq!(solution, 6)
     }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(parse::<&str>("AAA = (BBB, CCC)").is_err());
+        assert!(parse::<&str>("LR\n\nAAA = (AAA, ZZZ)").is_err());
+
+        let no_start = parse("LR\n\nBBB = (BBB, BBB)").unwrap();
+        assert!(solution_pt1(&no_start).is_err());
+
+        let cycle = parse("LR\n\nAAA = (AAA, AAA)\n11A = (11B, 11B)\n11B = (11A, 11A)\n").unwrap();
+        assert!(solution_pt1(&cycle).is_err());
+        assert!(solution_pt2(&cycle).is_err());
+    }
 }